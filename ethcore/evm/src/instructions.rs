@@ -174,6 +174,14 @@ enum_with_from_u8! {
 		GAS = 0x5a,
 		#[doc = "set a potential jump destination"]
 		JUMPDEST = 0x5b,
+		#[doc = "load word from transient storage"]
+		TLOAD = 0x5c,
+		#[doc = "save word to transient storage"]
+		TSTORE = 0x5d,
+		#[doc = "copy memory area"]
+		MCOPY = 0x5e,
+		#[doc = "place zero on stack"]
+		PUSH0 = 0x5f,
 
 		#[doc = "place 1 byte item on stack"]
 		PUSH1 = 0x60,
@@ -518,6 +526,10 @@ lazy_static! {
 		arr[MSIZE as usize] = Some(InstructionInfo::new("MSIZE", 0, 1, GasPriceTier::Base));
 		arr[GAS as usize] = Some(InstructionInfo::new("GAS", 0, 1, GasPriceTier::Base));
 		arr[JUMPDEST as usize] = Some(InstructionInfo::new("JUMPDEST", 0, 0, GasPriceTier::Special));
+		arr[TLOAD as usize] = Some(InstructionInfo::new("TLOAD", 1, 1, GasPriceTier::Special));
+		arr[TSTORE as usize] = Some(InstructionInfo::new("TSTORE", 2, 0, GasPriceTier::Special));
+		arr[MCOPY as usize] = Some(InstructionInfo::new("MCOPY", 3, 0, GasPriceTier::VeryLow));
+		arr[PUSH0 as usize] = Some(InstructionInfo::new("PUSH0", 0, 1, GasPriceTier::Base));
 		arr[PUSH1 as usize] = Some(InstructionInfo::new("PUSH1", 0, 1, GasPriceTier::VeryLow));
 		arr[PUSH2 as usize] = Some(InstructionInfo::new("PUSH2", 0, 1, GasPriceTier::VeryLow));
 		arr[PUSH3 as usize] = Some(InstructionInfo::new("PUSH3", 0, 1, GasPriceTier::VeryLow));
@@ -612,6 +624,7 @@ mod tests {
 		assert!(PUSH1.is_push());
 		assert!(PUSH32.is_push());
 		assert!(!DUP1.is_push());
+		assert!(!PUSH0.is_push());
 	}
 
 	#[test]
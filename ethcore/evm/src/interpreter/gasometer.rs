@@ -15,7 +15,7 @@
 // along with Parity.  If not, see <http://www.gnu.org/licenses/>.
 
 use std::cmp;
-use ethereum_types::{U256, H256};
+use ethereum_types::{U256, H256, Address};
 use super::u256_to_address;
 
 use {evm, vm};
@@ -125,7 +125,7 @@ impl<Gas: evm::CostType> Gasometer<Gas> {
 				let newval = stack.peek(1);
 				let val = U256::from(&*ext.storage_at(&address)?);
 
-				let gas = if schedule.eip1283 {
+				let mut gas = if schedule.eip1283 {
 					let orig = U256::from(&*ext.initial_storage_at(&address)?);
 					calculate_eip1283_sstore_gas(schedule, &orig, &val, &newval)
 				} else {
@@ -137,25 +137,44 @@ impl<Gas: evm::CostType> Gasometer<Gas> {
 						schedule.sstore_reset_gas
 					}
 				};
+				if schedule.eip2929 && !ext.al_contains_storage_key(&address) {
+					gas += schedule.cold_sload_cost;
+				}
 				Request::Gas(Gas::from(gas))
 			},
 			instructions::SLOAD => {
-				Request::Gas(Gas::from(schedule.sload_gas))
+				let key = H256::from(stack.peek(0));
+				let gas = if schedule.eip2929 && !ext.al_contains_storage_key(&key) {
+					schedule.cold_sload_cost
+				} else {
+					schedule.sload_gas
+				};
+				Request::Gas(Gas::from(gas))
+			},
+			instructions::TLOAD | instructions::TSTORE => {
+				// EIP-1153 prices transient storage access as a warm storage read
+				Request::Gas(Gas::from(schedule.warm_storage_read_cost))
 			},
 			instructions::BALANCE => {
-				Request::Gas(Gas::from(schedule.balance_gas))
+				let address = u256_to_address(stack.peek(0));
+				Request::Gas(Gas::from(account_access_gas(ext, &address, schedule.balance_gas)))
 			},
 			instructions::EXTCODESIZE => {
-				Request::Gas(Gas::from(schedule.extcodesize_gas))
+				let address = u256_to_address(stack.peek(0));
+				Request::Gas(Gas::from(account_access_gas(ext, &address, schedule.extcodesize_gas)))
 			},
 			instructions::EXTCODEHASH => {
-				Request::Gas(Gas::from(schedule.extcodehash_gas))
+				let address = u256_to_address(stack.peek(0));
+				Request::Gas(Gas::from(account_access_gas(ext, &address, schedule.extcodehash_gas)))
 			},
 			instructions::SUICIDE => {
 				let mut gas = Gas::from(schedule.suicide_gas);
 
 				let is_value_transfer = !ext.origin_balance()?.is_zero();
 				let address = u256_to_address(stack.peek(0));
+				if schedule.eip2929 && !ext.al_contains_address(&address) {
+					gas = overflowing!(gas.overflow_add(schedule.cold_account_access_cost.into()));
+				}
 				if (
 					!schedule.no_empty && !ext.exists(&address)?
 				) || (
@@ -183,8 +202,17 @@ impl<Gas: evm::CostType> Gasometer<Gas> {
 			instructions::CALLDATACOPY | instructions::CODECOPY | instructions::RETURNDATACOPY => {
 				Request::GasMemCopy(default_gas, mem_needed(stack.peek(0), stack.peek(2))?, Gas::from_u256(*stack.peek(2))?)
 			},
+			instructions::MCOPY => {
+				let mem = cmp::max(
+					mem_needed(stack.peek(0), stack.peek(2))?,
+					mem_needed(stack.peek(1), stack.peek(2))?
+				);
+				Request::GasMemCopy(default_gas, mem, Gas::from_u256(*stack.peek(2))?)
+			},
 			instructions::EXTCODECOPY => {
-				Request::GasMemCopy(schedule.extcodecopy_base_gas.into(), mem_needed(stack.peek(1), stack.peek(3))?, Gas::from_u256(*stack.peek(3))?)
+				let address = u256_to_address(stack.peek(0));
+				let base_gas = account_access_gas(ext, &address, schedule.extcodecopy_base_gas);
+				Request::GasMemCopy(base_gas.into(), mem_needed(stack.peek(1), stack.peek(3))?, Gas::from_u256(*stack.peek(3))?)
 			},
 			instructions::LOG0 | instructions::LOG1 | instructions::LOG2 | instructions::LOG3 | instructions::LOG4 => {
 				let no_of_topics = instruction.log_topics().expect("log_topics always return some for LOG* instructions; qed");
@@ -195,13 +223,13 @@ impl<Gas: evm::CostType> Gasometer<Gas> {
 				Request::GasMem(gas, mem_needed(stack.peek(0), stack.peek(1))?)
 			},
 			instructions::CALL | instructions::CALLCODE => {
-				let mut gas = Gas::from(schedule.call_gas);
+				let address = u256_to_address(stack.peek(1));
+				let mut gas = Gas::from(account_access_gas(ext, &address, schedule.call_gas));
 				let mem = cmp::max(
 					mem_needed(stack.peek(5), stack.peek(6))?,
 					mem_needed(stack.peek(3), stack.peek(4))?
 				);
 
-				let address = u256_to_address(stack.peek(1));
 				let is_value_transfer = !stack.peek(2).is_zero();
 
 				if instruction == instructions::CALL && (
@@ -221,7 +249,8 @@ impl<Gas: evm::CostType> Gasometer<Gas> {
 				Request::GasMemProvide(gas, mem, Some(requested))
 			},
 			instructions::DELEGATECALL | instructions::STATICCALL => {
-				let gas = Gas::from(schedule.call_gas);
+				let address = u256_to_address(stack.peek(1));
+				let gas = Gas::from(account_access_gas(ext, &address, schedule.call_gas));
 				let mem = cmp::max(
 					mem_needed(stack.peek(4), stack.peek(5))?,
 					mem_needed(stack.peek(2), stack.peek(3))?
@@ -367,6 +396,19 @@ fn to_word_size<Gas: evm::CostType>(value: Gas) -> (Gas, bool) {
 	(gas >> 5, false)
 }
 
+/// Price of accessing an account: `default` before EIP-2929, warm or cold access cost after.
+#[inline]
+fn account_access_gas(ext: &vm::Ext, address: &Address, default: usize) -> usize {
+	let schedule = ext.schedule();
+	if !schedule.eip2929 {
+		default
+	} else if ext.al_contains_address(address) {
+		schedule.warm_storage_read_cost
+	} else {
+		schedule.cold_account_access_cost
+	}
+}
+
 #[inline]
 fn calculate_eip1283_sstore_gas<Gas: evm::CostType>(schedule: &Schedule, original: &U256, current: &U256, new: &U256) -> Gas {
 	Gas::from(
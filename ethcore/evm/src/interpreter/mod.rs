@@ -328,6 +328,11 @@ impl<Cost: CostType> Interpreter<Cost> {
 				self.do_trace = self.do_trace && ext.trace_next_instruction(
 					self.reader.position - 1, opcode, self.gasometer.as_mut().expect(GASOMETER_PROOF).current_gas.as_u256(),
 				);
+				if self.do_trace {
+					ext.trace_step(
+						self.reader.position - 1, opcode, self.gasometer.as_mut().expect(GASOMETER_PROOF).current_gas.as_u256(), self.stack.peek_top(self.stack.size()),
+					);
+				}
 
 				let instruction = match instruction {
 					Some(i) => i,
@@ -421,7 +426,10 @@ impl<Cost: CostType> Interpreter<Cost> {
 			((instruction == instructions::RETURNDATACOPY || instruction == instructions::RETURNDATASIZE) && !schedule.have_return_data) ||
 			(instruction == instructions::REVERT && !schedule.have_revert) ||
 			((instruction == instructions::SHL || instruction == instructions::SHR || instruction == instructions::SAR) && !schedule.have_bitwise_shifting) ||
-			(instruction == instructions::EXTCODEHASH && !schedule.have_extcodehash)
+			(instruction == instructions::EXTCODEHASH && !schedule.have_extcodehash) ||
+			((instruction == instructions::TLOAD || instruction == instructions::TSTORE) && !schedule.have_transient_storage) ||
+			(instruction == instructions::PUSH0 && !schedule.have_push0) ||
+			(instruction == instructions::MCOPY && !schedule.have_mcopy)
 		{
 			return Err(vm::Error::BadInstruction {
 				instruction: instruction as u8
@@ -453,7 +461,7 @@ impl<Cost: CostType> Interpreter<Cost> {
 		let written = match instruction {
 			instructions::MSTORE | instructions::MLOAD => Some((read(0), 32)),
 			instructions::MSTORE8 => Some((read(0), 1)),
-			instructions::CALLDATACOPY | instructions::CODECOPY | instructions::RETURNDATACOPY => Some((read(0), read(2))),
+			instructions::CALLDATACOPY | instructions::CODECOPY | instructions::RETURNDATACOPY | instructions::MCOPY => Some((read(0), read(2))),
 			instructions::EXTCODECOPY => Some((read(1), read(3))),
 			instructions::CALL | instructions::CALLCODE => Some((read(5), read(6))),
 			instructions::DELEGATECALL | instructions::STATICCALL => Some((read(4), read(5))),
@@ -556,6 +564,9 @@ impl<Cost: CostType> Interpreter<Cost> {
 				let call_gas = provided.expect("`provided` comes through Self::exec from `Gasometer::get_gas_cost_mem`; `gas_gas_mem_cost` guarantees `Some` when instruction is `CALL`/`CALLCODE`/`DELEGATECALL`/`CREATE`; this is one of `CALL`/`CALLCODE`/`DELEGATECALL`; qed");
 				let code_address = self.stack.pop_back();
 				let code_address = u256_to_address(&code_address);
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(code_address.clone());
+				}
 
 				let value = if instruction == instructions::DELEGATECALL {
 					None
@@ -654,8 +665,11 @@ impl<Cost: CostType> Interpreter<Cost> {
 				return Ok(InstructionResult::StopExecution);
 			},
 			instructions::SUICIDE => {
-				let address = self.stack.pop_back();
-				ext.suicide(&u256_to_address(&address))?;
+				let address = u256_to_address(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(address.clone());
+				}
+				ext.suicide(&address)?;
 				return Ok(InstructionResult::StopExecution);
 			},
 			instructions::LOG0 | instructions::LOG1 | instructions::LOG2 | instructions::LOG3 | instructions::LOG4 => {
@@ -669,6 +683,9 @@ impl<Cost: CostType> Interpreter<Cost> {
 					.collect();
 				ext.log(topics, self.mem.read_slice(offset, size))?;
 			},
+			instructions::PUSH0 => {
+				self.stack.push(U256::zero());
+			},
 			instructions::PUSH1 | instructions::PUSH2 | instructions::PUSH3 | instructions::PUSH4 |
 			instructions::PUSH5 | instructions::PUSH6 | instructions::PUSH7 | instructions::PUSH8 |
 			instructions::PUSH9 | instructions::PUSH10 | instructions::PUSH11 | instructions::PUSH12 |
@@ -695,6 +712,14 @@ impl<Cost: CostType> Interpreter<Cost> {
 				let byte = self.stack.pop_back();
 				self.mem.write_byte(offset, byte);
 			},
+			instructions::MCOPY => {
+				let dest_offset = self.stack.pop_back();
+				let source_offset = self.stack.pop_back();
+				let size = self.stack.pop_back();
+				// regions may overlap, copy out of memory first
+				let data = self.mem.read_slice(source_offset, size).to_vec();
+				self.mem.write_slice(dest_offset, &data);
+			},
 			instructions::MSIZE => {
 				self.stack.push(U256::from(self.mem.size()));
 			},
@@ -706,12 +731,18 @@ impl<Cost: CostType> Interpreter<Cost> {
 			},
 			instructions::SLOAD => {
 				let key = H256::from(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_storage_key(key.clone());
+				}
 				let word = U256::from(&*ext.storage_at(&key)?);
 				self.stack.push(word);
 			},
 			instructions::SSTORE => {
 				let address = H256::from(&self.stack.pop_back());
 				let val = self.stack.pop_back();
+				if ext.schedule().eip2929 {
+					ext.al_insert_storage_key(address.clone());
+				}
 
 				let current_val = U256::from(&*ext.storage_at(&address)?);
 				// Increase refund for clear
@@ -726,6 +757,16 @@ impl<Cost: CostType> Interpreter<Cost> {
 				}
 				ext.set_storage(address, H256::from(&val))?;
 			},
+			instructions::TLOAD => {
+				let key = H256::from(&self.stack.pop_back());
+				let word = U256::from(&*ext.transient_storage_at(&key)?);
+				self.stack.push(word);
+			},
+			instructions::TSTORE => {
+				let key = H256::from(&self.stack.pop_back());
+				let val = self.stack.pop_back();
+				ext.set_transient_storage(key, H256::from(&val))?;
+			},
 			instructions::PC => {
 				self.stack.push(U256::from(self.reader.position - 1));
 			},
@@ -740,6 +781,9 @@ impl<Cost: CostType> Interpreter<Cost> {
 			},
 			instructions::BALANCE => {
 				let address = u256_to_address(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(address.clone());
+				}
 				let balance = ext.balance(&address)?;
 				self.stack.push(balance);
 			},
@@ -779,11 +823,17 @@ impl<Cost: CostType> Interpreter<Cost> {
 			},
 			instructions::EXTCODESIZE => {
 				let address = u256_to_address(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(address.clone());
+				}
 				let len = ext.extcodesize(&address)?.unwrap_or(0);
 				self.stack.push(U256::from(len));
 			},
 			instructions::EXTCODEHASH => {
 				let address = u256_to_address(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(address.clone());
+				}
 				let hash = ext.extcodehash(&address)?.unwrap_or_else(H256::zero);
 				self.stack.push(U256::from(hash));
 			},
@@ -806,6 +856,9 @@ impl<Cost: CostType> Interpreter<Cost> {
 			},
 			instructions::EXTCODECOPY => {
 				let address = u256_to_address(&self.stack.pop_back());
+				if ext.schedule().eip2929 {
+					ext.al_insert_address(address.clone());
+				}
 				let code = ext.extcode(&address)?;
 				Self::copy_data_to_memory(
 					&mut self.mem,
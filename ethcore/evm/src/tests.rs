@@ -1029,6 +1029,266 @@ fn test_sar(factory: super::Factory) {
 		"0000000000000000000000000000000000000000000000000000000000000000");
 }
 
+evm_test!{test_eip2929_cold_and_warm_access: test_eip2929_cold_and_warm_access_int}
+fn test_eip2929_cold_and_warm_access(factory: super::Factory) {
+	let address = Address::from_str("0f572e5295c57f15886f9b263e2f6d2d6c7b5ec6").unwrap();
+	// SLOAD(0) twice, then BALANCE(0x0f57..) twice
+	let code = "6000545060005450730f572e5295c57f15886f9b263e2f6d2d6c7b5ec63150730f572e5295c57f15886f9b263e2f6d2d6c7b5ec63150".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+	ext.balances.insert(address.clone(), U256::from(1));
+
+	let gas_left = {
+		let mut vm = factory.create(params.clone(), ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// cold SLOAD (2100), warm SLOAD (100), cold BALANCE (2600), warm BALANCE (100)
+	assert_eq!(gas_left, U256::from(100_000 - 4_920));
+	assert_set_contains(&ext.accessed_addresses, &address);
+	assert_set_contains(&ext.accessed_storage_keys, &H256::from(0));
+
+	// everything is warm on the second run
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	assert_eq!(gas_left, U256::from(100_000 - 420));
+}
+
+evm_test!{test_eip2929_sstore: test_eip2929_sstore_int}
+fn test_eip2929_sstore(factory: super::Factory) {
+	// SSTORE(0, 1), SSTORE(0, 2), SSTORE(1, 0)
+	let code = "600160005560026000556000600155".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// cold set (20000 + 2100), warm reset (2900), cold reset (2900 + 2100)
+	assert_eq!(gas_left, U256::from(100_000 - 30_018));
+	assert_set_contains(&ext.accessed_storage_keys, &H256::from(0));
+	assert_set_contains(&ext.accessed_storage_keys, &H256::from(1));
+}
+
+evm_test!{test_eip2929_extcodecopy: test_eip2929_extcodecopy_int}
+fn test_eip2929_extcodecopy(factory: super::Factory) {
+	// EXTCODECOPY(0xff) twice, then EXTCODECOPY(ADDRESS); example from EIP-2929
+	let code = "60006000600060ff3c60006000600060ff3c600060006000303c00".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+	// the executing contract is always warm
+	ext.accessed_addresses.insert(params.address.clone());
+
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// cold (2600), warm (100), warm (100)
+	assert_eq!(gas_left, U256::from(100_000 - 2_835));
+	assert_set_contains(&ext.accessed_addresses, &Address::from(0xff));
+}
+
+evm_test!{test_eip2929_account_access: test_eip2929_account_access_int}
+fn test_eip2929_account_access(factory: super::Factory) {
+	// EXTCODEHASH, EXTCODESIZE and BALANCE of precompiles 1-3, then of 0xf1-0xf3 twice,
+	// then BALANCE(ORIGIN) and BALANCE(ADDRESS); example from EIP-2929
+	let code = "60013f5060023b506003315060f13f5060f23b5060f3315060f23f5060f33b5060f1315032315030315000".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+	// precompiles, origin and the executing contract are warm at the start of a transaction
+	for address in &[Address::from(1), Address::from(2), Address::from(3), params.origin.clone(), params.address.clone()] {
+		ext.accessed_addresses.insert(address.clone());
+	}
+	for address in &[Address::from(3), Address::from(0xf1), Address::from(0xf3), params.address.clone()] {
+		ext.balances.insert(address.clone(), U256::zero());
+	}
+
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// 3 warm (100), 3 cold (2600), 5 warm (100)
+	assert_eq!(gas_left, U256::from(100_000 - 8_653));
+	assert_set_contains(&ext.accessed_addresses, &Address::from(0xf1));
+	assert_set_contains(&ext.accessed_addresses, &Address::from(0xf2));
+	assert_set_contains(&ext.accessed_addresses, &Address::from(0xf3));
+}
+
+evm_test!{test_eip2929_sload_after_sstore: test_eip2929_sload_after_sstore_int}
+fn test_eip2929_sload_after_sstore(factory: super::Factory) {
+	// SSTORE(0, 0), SLOAD(0) twice
+	let code = "60006000556000545060005450".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// cold no-op SSTORE (2100 + 100), warm SLOAD (100) twice
+	assert_eq!(gas_left, U256::from(100_000 - 2_416));
+	assert_set_contains(&ext.accessed_storage_keys, &H256::from(0));
+}
+
+evm_test!{test_eip2929_call: test_eip2929_call_int}
+fn test_eip2929_call(factory: super::Factory) {
+	// CALL(0, 0xff, 0, 0, 0, 0, 0) twice
+	let code = "6000600060006000600060ff6000f1506000600060006000600060ff6000f15000".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_eip2929();
+	ext.balances.insert(params.address.clone(), U256::zero());
+
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	// cold (2600), warm (100)
+	assert_eq!(gas_left, U256::from(100_000 - 2_746));
+	assert_set_contains(&ext.accessed_addresses, &Address::from(0xff));
+}
+
+evm_test!{test_transient_storage: test_transient_storage_int}
+fn test_transient_storage(factory: super::Factory) {
+	// TSTORE(0, 0x2a), SSTORE(0, TLOAD(0))
+	let code = "602a60005d60005c600055".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+
+	let mut ext = FakeExt::new_constantinople();
+	let err = {
+		let mut vm = factory.create(params.clone(), ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap_err()
+	};
+	assert_eq!(err, vm::Error::BadInstruction { instruction: 0x5d });
+
+	let mut ext = FakeExt::new_constantinople();
+	ext.schedule.have_transient_storage = true;
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	assert_eq!(gas_left, U256::from(100_000 - 20_212));
+	assert_store(&ext, 0, "000000000000000000000000000000000000000000000000000000000000002a");
+	assert_eq!(ext.transient_store.get(&H256::from(0)), Some(&H256::from(0x2a)));
+}
+
+evm_test!{test_push0: test_push0_int}
+fn test_push0(factory: super::Factory) {
+	// SSTORE(PUSH0, 1)
+	let code = "60015f55".from_hex().unwrap();
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+
+	let mut ext = FakeExt::new_constantinople();
+	let err = {
+		let mut vm = factory.create(params.clone(), ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap_err()
+	};
+	assert_eq!(err, vm::Error::BadInstruction { instruction: 0x5f });
+
+	let mut ext = FakeExt::new_constantinople();
+	ext.schedule.have_push0 = true;
+	let gas_left = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap()
+	};
+
+	assert_eq!(gas_left, U256::from(100_000 - 20_005));
+	assert_store(&ext, 0, "0000000000000000000000000000000000000000000000000000000000000001");
+}
+
+evm_test!{test_push0_stack_limit: test_push0_stack_limit_int}
+fn test_push0_stack_limit(factory: super::Factory) {
+	let mut ext = FakeExt::new_constantinople();
+	ext.schedule.have_push0 = true;
+	let code = vec![0x5f; ext.schedule.stack_limit + 1];
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+
+	let err = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap()).unwrap_err()
+	};
+
+	assert_eq!(err, vm::Error::OutOfStack { instruction: "PUSH0", wanted: 1, limit: 1024 });
+}
+
+evm_test!{test_mcopy: test_mcopy_int}
+fn test_mcopy(factory: super::Factory) {
+	let word = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+	// MSTORE(0, word), MCOPY(32, 0, 32), SSTORE(0, MLOAD(32))
+	let gas_left = mcopy_test(&factory, &format!("7f{}6000526020600060205e6020516000", word), Some(word));
+	assert_eq!(gas_left, Ok(U256::from(100_000 - 20_039)));
+
+	// overlapping: MSTORE(0, word), MCOPY(1, 0, 32), SSTORE(0, MLOAD(0))
+	let _ = mcopy_test(&factory, &format!("7f{}6000526020600060015e6000516000", word), Some("0000000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e"));
+
+	// zero length does not expand memory: MCOPY(0xffff, 0xffff, 0), SSTORE(0, MSIZE)
+	let _ = mcopy_test(&factory, "600061ffff61ffff5e596000", Some("0000000000000000000000000000000000000000000000000000000000000000"));
+
+	// huge length runs out of gas
+	let res = mcopy_test(&factory, "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff600060005e", None);
+	assert_eq!(res, Err(vm::Error::OutOfGas));
+}
+
+fn mcopy_test(factory: &super::Factory, code: &str, result: Option<&str>) -> vm::Result<U256> {
+	let mut code = code.from_hex().unwrap();
+	if result.is_some() {
+		code.push(0x55);
+	}
+
+	let mut params = ActionParams::default();
+	params.gas = U256::from(100_000);
+	params.code = Some(Arc::new(code));
+	let mut ext = FakeExt::new_constantinople();
+	ext.schedule.have_mcopy = true;
+
+	let res = {
+		let mut vm = factory.create(params, ext.schedule(), ext.depth());
+		test_finalize(vm.exec(&mut ext).ok().unwrap())
+	};
+
+	if let Some(result) = result {
+		assert_store(&ext, 0, result);
+	}
+	res
+}
+
 fn push_two_pop_one_constantinople_test(factory: &super::Factory, opcode: u8, push1: &str, push2: &str, result: &str) {
 	let mut push1 = push1.from_hex().unwrap();
 	let mut push2 = push2.from_hex().unwrap();
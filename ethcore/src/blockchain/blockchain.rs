@@ -564,6 +564,7 @@ impl BlockChain {
 					parent: header.parent_hash(),
 					children: vec![],
 					is_finalized: false,
+					empty_steps: 0,
 				};
 
 				let mut batch = DBTransaction::new();
@@ -842,6 +843,7 @@ impl BlockChain {
 				parent: block_parent_hash,
 				children: Vec::new(),
 				is_finalized: false,
+				empty_steps: 0,
 			};
 
 			let mut update = HashMap::new();
@@ -1008,7 +1010,7 @@ impl BlockChain {
 
 		self.prepare_update(batch, ExtrasUpdate {
 			block_hashes: self.prepare_block_hashes_update(&info),
-			block_details: self.prepare_block_details_update(parent_hash, &info, extras.is_finalized),
+			block_details: self.prepare_block_details_update(parent_hash, &info, extras.is_finalized, extras.empty_steps),
 			block_receipts: self.prepare_block_receipts_update(receipts, &info),
 			blocks_blooms: self.prepare_block_blooms_update(block.header_view().log_bloom(), &info),
 			transactions_addresses: self.prepare_transaction_addresses_update(block.view().transaction_hashes(), &info),
@@ -1241,7 +1243,7 @@ impl BlockChain {
 
 	/// This function returns modified block details.
 	/// Uses the given parent details or attempts to load them from the database.
-	fn prepare_block_details_update(&self, parent_hash: H256, info: &BlockInfo, is_finalized: bool) -> HashMap<H256, BlockDetails> {
+	fn prepare_block_details_update(&self, parent_hash: H256, info: &BlockInfo, is_finalized: bool, empty_steps: usize) -> HashMap<H256, BlockDetails> {
 		// update parent
 		let mut parent_details = self.block_details(&parent_hash).unwrap_or_else(|| panic!("Invalid parent hash: {:?}", parent_hash));
 		parent_details.children.push(info.hash);
@@ -1253,6 +1255,7 @@ impl BlockChain {
 			parent: parent_hash,
 			children: vec![],
 			is_finalized: is_finalized,
+			empty_steps: empty_steps,
 		};
 
 		// write to batch
@@ -1530,6 +1533,7 @@ mod tests {
 		bc.insert_block(batch, block, receipts, ExtrasInsert {
 			fork_choice: fork_choice,
 			is_finalized: false,
+			empty_steps: 0,
 		})
 	}
 
@@ -1587,6 +1591,38 @@ mod tests {
 		assert_eq!(bc.block_hash(2), None);
 	}
 
+	#[test]
+	fn stores_empty_steps_in_block_details() {
+		use blockchain::ExtrasInsert;
+
+		let genesis = BlockBuilder::genesis();
+		let first = genesis.add_block();
+		let first_hash = first.last().hash();
+
+		let db = new_db();
+		let bc = new_chain(genesis.last().encoded(), db.clone());
+
+		let mut batch = db.key_value().transaction();
+		bc.insert_block(&mut batch, first.last().encoded(), vec![], ExtrasInsert {
+			fork_choice: ::engines::ForkChoice::New,
+			is_finalized: false,
+			empty_steps: 3,
+		});
+		db.key_value().write(batch).unwrap();
+		bc.commit();
+		assert_eq!(bc.block_details(&first_hash).unwrap().empty_steps, 3);
+
+		let mut batch = db.key_value().transaction();
+		bc.mark_finalized(&mut batch, first_hash).unwrap();
+		db.key_value().write(batch).unwrap();
+
+		// the count survives finalization and reopening the database
+		let bc = new_chain(genesis.last().encoded(), db.clone());
+		let details = bc.block_details(&first_hash).unwrap();
+		assert!(details.is_finalized);
+		assert_eq!(details.empty_steps, 3);
+	}
+
 	#[test]
 	fn check_ancestry_iter() {
 		let genesis = BlockBuilder::genesis();
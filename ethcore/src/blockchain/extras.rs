@@ -152,44 +152,54 @@ pub struct BlockDetails {
 	pub children: Vec<H256>,
 	/// Whether the block is considered finalized
 	pub is_finalized: bool,
+	/// Number of empty step messages sealed in the block
+	pub empty_steps: usize,
 }
 
 impl rlp::Encodable for BlockDetails {
 	fn rlp_append(&self, stream: &mut rlp::RlpStream) {
-		let use_short_version = !self.is_finalized;
-
-		match use_short_version {
-			true => { stream.begin_list(4); },
-			false => { stream.begin_list(5); },
-		}
+		// older entries only have the fields up to the last non-default one
+		let item_count = match (self.is_finalized, self.empty_steps) {
+			(false, 0) => 4,
+			(true, 0) => 5,
+			_ => 6,
+		};
 
+		stream.begin_list(item_count);
 		stream.append(&self.number);
 		stream.append(&self.total_difficulty);
 		stream.append(&self.parent);
 		stream.append_list(&self.children);
-		if !use_short_version {
+		if item_count > 4 {
 			stream.append(&self.is_finalized);
 		}
+		if item_count > 5 {
+			stream.append(&self.empty_steps);
+		}
 	}
 }
 
 impl rlp::Decodable for BlockDetails {
 	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
-		let use_short_version = match rlp.item_count()? {
-			4 => true,
-			5 => false,
-			_ => return Err(rlp::DecoderError::RlpIncorrectListLen),
-		};
+		let item_count = rlp.item_count()?;
+		if item_count < 4 || item_count > 6 {
+			return Err(rlp::DecoderError::RlpIncorrectListLen);
+		}
 
 		Ok(BlockDetails {
 			number: rlp.val_at(0)?,
 			total_difficulty: rlp.val_at(1)?,
 			parent: rlp.val_at(2)?,
 			children: rlp.list_at(3)?,
-			is_finalized: if use_short_version {
+			is_finalized: if item_count > 4 {
+				rlp.val_at(4)?
+			} else {
 				false
+			},
+			empty_steps: if item_count > 5 {
+				rlp.val_at(5)?
 			} else {
-				rlp.val_at(4)?
+				0
 			},
 		})
 	}
@@ -245,7 +255,7 @@ pub struct EpochTransitions {
 mod tests {
 	use rlp::*;
 
-	use super::BlockReceipts;
+	use super::{BlockReceipts, BlockDetails};
 
 	#[test]
 	fn encode_block_receipts() {
@@ -258,4 +268,26 @@ mod tests {
 		assert!(s.is_finished(), "List should be finished now");
 		s.out();
 	}
+
+	#[test]
+	fn encode_block_details() {
+		let details = |is_finalized, empty_steps| BlockDetails {
+			number: 10,
+			total_difficulty: 100.into(),
+			parent: 5.into(),
+			children: vec![6.into()],
+			is_finalized,
+			empty_steps,
+		};
+
+		for &(is_finalized, empty_steps, item_count) in &[(false, 0, 4), (true, 0, 5), (false, 3, 6), (true, 3, 6)] {
+			let encoded = encode(&details(is_finalized, empty_steps));
+			assert_eq!(Rlp::new(&encoded).item_count().unwrap(), item_count);
+
+			let decoded: BlockDetails = decode(&encoded).unwrap();
+			assert_eq!(decoded.is_finalized, is_finalized);
+			assert_eq!(decoded.empty_steps, empty_steps);
+			assert_eq!(decoded.children, vec![6.into()]);
+		}
+	}
 }
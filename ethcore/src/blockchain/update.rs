@@ -45,4 +45,6 @@ pub struct ExtrasInsert {
 	pub fork_choice: ::engines::ForkChoice,
 	/// Is the inserted block considered finalized.
 	pub is_finalized: bool,
+	/// Number of empty step messages sealed in the inserted block.
+	pub empty_steps: usize,
 }
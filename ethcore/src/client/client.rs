@@ -32,7 +32,7 @@ use kvdb::{DBValue, KeyValueDB, DBTransaction};
 // other
 use ethereum_types::{H256, Address, U256};
 use block::{IsBlock, LockedBlock, Drain, ClosedBlock, OpenBlock, enact_verified, SealedBlock};
-use blockchain::{BlockChain, BlockChainDB, BlockProvider, TreeRoute, ImportRoute, TransactionAddress, ExtrasInsert};
+use blockchain::{BlockChain, BlockChainDB, BlockDetails, BlockProvider, TreeRoute, ImportRoute, TransactionAddress, ExtrasInsert};
 use client::ancient_import::AncientVerifier;
 use client::{
 	Nonce, Balance, ChainInfo, BlockInfo, CallContract, TransactionInfo,
@@ -552,6 +552,7 @@ impl Importer {
 		let route = chain.insert_block(&mut batch, block_data, receipts.clone(), ExtrasInsert {
 			fork_choice: fork_choice,
 			is_finalized,
+			empty_steps: self.engine.empty_steps_count(header),
 		});
 
 		client.tracedb.read().import(&mut batch, TraceImportRequest {
@@ -1173,6 +1174,27 @@ impl Client {
 		self.history
 	}
 
+	/// Execute a transaction like `Call::call`, reporting every executed instruction to given step tracer.
+	/// The tracer is handed back in the `vm_trace` of the result.
+	pub fn call_with_tracer<T: trace::StepTracer>(&self, transaction: &SignedTransaction, tracer: T, state: &mut State<StateDB>, header: &Header) -> Result<Executed<trace::FlatTrace, T>, CallError> {
+		let env_info = EnvInfo {
+			number: header.number(),
+			author: header.author().clone(),
+			timestamp: header.timestamp(),
+			difficulty: header.difficulty().clone(),
+			last_hashes: self.build_last_hashes(header.parent_hash()),
+			gas_used: U256::default(),
+			gas_limit: U256::max_value(),
+		};
+		let machine = self.engine.machine();
+		let schedule = machine.schedule(env_info.number);
+		let options = TransactOptions::new(trace::NoopTracer, trace::StepVMTracer::new(tracer))
+			.dont_check_nonce()
+			.save_output_from_contract();
+
+		Ok(Executive::new(state, &env_info, machine, &schedule).transact_virtual(transaction, options)?)
+	}
+
 	fn block_hash(chain: &BlockChain, id: BlockId) -> Option<H256> {
 		match id {
 			BlockId::Hash(hash) => Some(hash),
@@ -1510,22 +1532,25 @@ impl Call for Client {
 			let schedule = machine.schedule(env_info.number);
 			Executive::new(&mut clone, &env_info, &machine, &schedule)
 				.transact_virtual(&tx, options())
-				.ok()
-				.map(|r| r.exception.is_none())
+				.map(|r| match r.exception {
+					None => Ok(()),
+					Some(exception) => Err((exception, r.output)),
+				})
 		};
 
-		let cond = |gas| exec(gas).unwrap_or(false);
+		let cond = |gas| exec(gas).map(|r| r.is_ok()).unwrap_or(false);
 
 		if !cond(upper) {
 			upper = max_upper;
 			match exec(upper) {
-				Some(false) => return Err(CallError::Exceptional),
-				None => {
+				Ok(Ok(())) => {},
+				Ok(Err((::vm::Error::Reverted, output))) => return Err(CallError::Reverted(output)),
+				Ok(Err((::vm::Error::OutOfGas, _))) | Err(ExecutionError::NotEnoughBaseGas { .. }) => {
 					trace!(target: "estimate_gas", "estimate_gas failed with {}", upper);
-					let err = ExecutionError::Internal(format!("Requires higher than upper limit of {}", upper));
-					return Err(err.into())
+					return Err(CallError::ExceedsGasLimit(upper))
 				},
-				_ => {},
+				Ok(Err((exception, _))) => return Err(CallError::Exceptional(exception)),
+				Err(err) => return Err(err.into()),
 			}
 		}
 		let lower = t.gas_required(&self.engine.schedule(env_info.number)).into();
@@ -1675,6 +1700,12 @@ impl BlockChainClient for Client {
 		Self::block_hash(&chain, id).and_then(|hash| chain.block_details(&hash)).map(|d| d.total_difficulty)
 	}
 
+	fn block_details(&self, id: BlockId) -> Option<BlockDetails> {
+		let chain = self.chain.read();
+
+		Self::block_hash(&chain, id).and_then(|hash| chain.block_details(&hash))
+	}
+
 	fn storage_root(&self, address: &Address, id: BlockId) -> Option<H256> {
 		self.state_at(id).and_then(|s| s.storage_root(address).ok()).and_then(|x| x)
 	}
@@ -2488,6 +2519,7 @@ mod tests {
 				another_client.chain.read().insert_block(&mut batch, encoded::Block::new(new_block), Vec::new(), ExtrasInsert {
 					fork_choice: ::engines::ForkChoice::New,
 					is_finalized: false,
+					empty_steps: 0,
 				});
 				go_thread.store(true, Ordering::SeqCst);
 			});
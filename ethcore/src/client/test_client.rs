@@ -33,7 +33,7 @@ use rlp::{Rlp, RlpStream};
 use ethkey::{Generator, Random};
 use ethcore_miner::pool::VerifiedTransaction;
 use transaction::{self, Transaction, LocalizedTransaction, SignedTransaction, Action};
-use blockchain::{TreeRoute, BlockReceipts};
+use blockchain::{TreeRoute, BlockReceipts, BlockDetails};
 use client::{
 	Nonce, Balance, ChainInfo, BlockInfo, ReopenBlock, CallContract, TransactionInfo, RegistryInfo,
 	PrepareOpenBlock, BlockChainClient, BlockChainInfo, BlockStatus, BlockId, Mode,
@@ -585,7 +585,10 @@ impl Call for TestBlockChainClient {
 	}
 
 	fn estimate_gas(&self, _t: &SignedTransaction, _state: &Self::State, _header: &Header) -> Result<U256, CallError> {
-		Ok(21000.into())
+		match *self.execution_result.read() {
+			Some(Err(ref e)) => Err(e.clone()),
+			_ => Ok(21000.into()),
+		}
 	}
 }
 
@@ -641,6 +644,19 @@ impl BlockChainClient for TestBlockChainClient {
 		Some(U256::zero())
 	}
 
+	fn block_details(&self, id: BlockId) -> Option<BlockDetails> {
+		BlockInfo::block_header(self, id)
+			.map(|header| header.decode().expect("decoding failed"))
+			.map(|header| BlockDetails {
+				number: header.number(),
+				total_difficulty: U256::zero(),
+				parent: *header.parent_hash(),
+				children: Vec::new(),
+				is_finalized: false,
+				empty_steps: self.spec.engine.empty_steps_count(&header),
+			})
+	}
+
 	fn block_hash(&self, id: BlockId) -> Option<H256> {
 		Self::block_hash(self, id)
 	}
@@ -20,7 +20,7 @@ use std::sync::Arc;
 use itertools::Itertools;
 
 use block::{OpenBlock, SealedBlock, ClosedBlock};
-use blockchain::TreeRoute;
+use blockchain::{BlockDetails, TreeRoute};
 use client::Mode;
 use encoded;
 use vm::LastHashes;
@@ -233,6 +233,9 @@ pub trait BlockChainClient : Sync + Send + AccountData + BlockChain + CallContra
 	/// Get block total difficulty.
 	fn block_total_difficulty(&self, id: BlockId) -> Option<U256>;
 
+	/// Get the familial details of a block, including its finality and number of empty steps.
+	fn block_details(&self, id: BlockId) -> Option<BlockDetails>;
+
 	/// Attempt to get address storage root at given block.
 	/// May not fail on BlockId::Latest.
 	fn storage_root(&self, address: &Address, id: BlockId) -> Option<H256>;
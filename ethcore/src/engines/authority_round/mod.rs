@@ -81,6 +81,10 @@ pub struct AuthorityRoundParams {
 	pub empty_steps_transition: u64,
 	/// Number of accepted empty steps.
 	pub maximum_empty_steps: usize,
+	/// Empty steps per block limit transition block.
+	pub empty_steps_limit_transition: u64,
+	/// Maximum number of empty steps per block, twice the number of validators if not set.
+	pub maximum_empty_steps_per_block: Option<usize>,
 }
 
 const U16_MAX: usize = ::std::u16::MAX as usize;
@@ -110,6 +114,8 @@ impl From<ethjson::spec::AuthorityRoundParams> for AuthorityRoundParams {
 			maximum_uncle_count: p.maximum_uncle_count.map_or(0, Into::into),
 			empty_steps_transition: p.empty_steps_transition.map_or(u64::max_value(), |n| ::std::cmp::max(n.into(), 1)),
 			maximum_empty_steps: p.maximum_empty_steps.map_or(0, Into::into),
+			empty_steps_limit_transition: p.empty_steps_limit_transition.map_or(u64::max_value(), Into::into),
+			maximum_empty_steps_per_block: p.maximum_empty_steps_per_block.map(Into::into),
 		}
 	}
 }
@@ -363,6 +369,7 @@ pub fn empty_step_rlp(step: usize, parent_hash: &H256) -> Vec<u8> {
 /// the `parent_hash` in order to save space. The included signature is of the original empty step
 /// message, which can be reconstructed by using the parent hash of the block in which this sealed
 /// empty message is included.
+#[derive(Clone)]
 struct SealedEmptyStep {
 	signature: H520,
 	step: usize,
@@ -409,6 +416,8 @@ pub struct AuthorityRound {
 	maximum_uncle_count: usize,
 	empty_steps_transition: u64,
 	maximum_empty_steps: usize,
+	empty_steps_limit_transition: u64,
+	maximum_empty_steps_per_block: Option<usize>,
 	machine: EthereumMachine,
 }
 
@@ -519,6 +528,18 @@ fn header_empty_steps(header: &Header) -> Result<Vec<EmptyStep>, ::rlp::DecoderE
 	Ok(empty_steps.into_iter().map(|s| EmptyStep::from_sealed(s, header.parent_hash())).collect())
 }
 
+// `pruneEmptySteps`: orders the empty steps by step, drops repeated ones and keeps at most `limit`.
+// Only the proposer of a step can sign its empty step, so messages for the same step always come
+// from the same validator.
+fn prune_empty_steps(mut empty_steps: Vec<EmptyStep>, limit: Option<usize>) -> Vec<EmptyStep> {
+	empty_steps.sort_by_key(|e| e.step);
+	empty_steps.dedup_by_key(|e| e.step);
+	if let Some(limit) = limit {
+		empty_steps.truncate(limit);
+	}
+	empty_steps
+}
+
 // gets the signers of empty step messages for the given header, does not include repeated signers
 fn header_empty_steps_signers(header: &Header, empty_steps_transition: u64) -> Result<Vec<Address>, Error> {
 	if header.number() >= empty_steps_transition {
@@ -661,6 +682,8 @@ impl AuthorityRound {
 				maximum_uncle_count: our_params.maximum_uncle_count,
 				empty_steps_transition: our_params.empty_steps_transition,
 				maximum_empty_steps: our_params.maximum_empty_steps,
+				empty_steps_limit_transition: our_params.empty_steps_limit_transition,
+				maximum_empty_steps_per_block: our_params.maximum_empty_steps_per_block,
 				machine: machine,
 			});
 
@@ -707,6 +730,26 @@ impl AuthorityRound {
 		}).cloned().collect()
 	}
 
+	/// Maximum number of empty steps in `header`, if limited at its number.
+	fn empty_steps_limit(&self, header: &Header, validators: &ValidatorSet) -> Option<usize> {
+		if header.number() >= self.empty_steps_limit_transition {
+			Some(self.maximum_empty_steps_per_block.unwrap_or_else(|| 2 * validators.count(header.parent_hash())))
+		} else {
+			None
+		}
+	}
+
+	/// Empty steps to include in the new block `header`, pruned and capped.
+	fn sealed_empty_steps(&self, header: &Header, parent_step: U256, step: U256) -> Vec<EmptyStep> {
+		let empty_steps = self.empty_steps(parent_step, step, *header.parent_hash());
+		let limit = match self.epoch_set(header) {
+			Ok((validators, _)) => self.empty_steps_limit(header, &*validators),
+			// the block can't be sealed without the validator set anyway
+			Err(_) => None,
+		};
+		prune_empty_steps(empty_steps, limit)
+	}
+
 	fn clear_empty_steps(&self, step: U256) {
 		// clear old `empty_steps` messages
 		self.empty_steps.lock().retain(|e| U256::from(e.step) > step);
@@ -714,7 +757,11 @@ impl AuthorityRound {
 
 	fn handle_empty_step_message(&self, empty_step: EmptyStep) {
 		let mut empty_steps = self.empty_steps.lock();
-		empty_steps.push(empty_step);
+		// every step has a single proposer, so a verified message for a step and parent we
+		// already have is a duplicate (e.g. our own message gossiped back to us).
+		if !empty_steps.iter().any(|e| e.step == empty_step.step && e.parent_hash == empty_step.parent_hash) {
+			empty_steps.push(empty_step);
+		}
 	}
 
 	fn generate_empty_step(&self, parent_hash: &H256) {
@@ -889,6 +936,14 @@ impl Engine<EthereumMachine> for AuthorityRound {
 		}
 	}
 
+	fn empty_steps_count(&self, header: &Header) -> usize {
+		if header.number() >= self.empty_steps_transition {
+			header_empty_steps(header).map(|e| e.len()).unwrap_or(0)
+		} else {
+			0
+		}
+	}
+
 	/// Additional engine-specific information for the user/developer concerning `header`.
 	fn extra_info(&self, header: &Header) -> BTreeMap<String, String> {
 		if header.seal().len() < header_expected_seal_fields(header, self.empty_steps_transition) {
@@ -935,7 +990,7 @@ impl Engine<EthereumMachine> for AuthorityRound {
 		let current_step = self.step.inner.load();
 
 		let current_empty_steps_len = if header.number() >= self.empty_steps_transition {
-			self.empty_steps(parent_step.into(), current_step.into(), parent.hash()).len()
+			self.sealed_empty_steps(header, parent_step.into(), current_step.into()).len()
 		} else {
 			0
 		};
@@ -991,7 +1046,7 @@ impl Engine<EthereumMachine> for AuthorityRound {
 
 		// filter messages from old and future steps and different parents
 		let empty_steps = if header.number() >= self.empty_steps_transition {
-			self.empty_steps(parent_step.into(), step.into(), *header.parent_hash())
+			self.sealed_empty_steps(header, parent_step, step.into())
 		} else {
 			Vec::new()
 		};
@@ -1026,11 +1081,12 @@ impl Engine<EthereumMachine> for AuthorityRound {
 			}
 
 			// if there are no transactions to include in the block, we don't seal and instead broadcast a signed
-			// `EmptyStep(step, parent_hash)` message. If we exceed the maximum amount of `empty_step` rounds we proceed
-			// with the seal.
+			// `EmptyStep(step, parent_hash)` message. If we exceed the maximum amount of `empty_step` rounds, or
+			// the block can't hold any more empty steps, we proceed with the seal.
 			if header.number() >= self.empty_steps_transition &&
 				block.transactions().is_empty() &&
-				empty_steps.len() < self.maximum_empty_steps {
+				empty_steps.len() < self.maximum_empty_steps &&
+				self.empty_steps_limit(header, &*validators).map_or(true, |limit| empty_steps.len() < limit) {
 
 				self.generate_empty_step(header.parent_hash());
 				return Seal::None;
@@ -1133,7 +1189,7 @@ impl Engine<EthereumMachine> for AuthorityRound {
 
 				let parent_step = header_step(&parent, self.empty_steps_transition)?;
 				let current_step = self.step.inner.load();
-				self.empty_steps(parent_step.into(), current_step.into(), parent.hash())
+				self.sealed_empty_steps(block.header(), parent_step.into(), current_step.into())
 			} else {
 				// we're verifying a block, extract empty steps from the seal
 				header_empty_steps(block.header())?
@@ -1215,7 +1271,22 @@ impl Engine<EthereumMachine> for AuthorityRound {
 			let validate_empty_steps = || -> Result<usize, Error> {
 				let empty_steps = header_empty_steps(header)?;
 				let empty_steps_len = empty_steps.len();
+				let empty_steps_limit = self.empty_steps_limit(header, &*validators);
+				if let Some(limit) = empty_steps_limit {
+					if empty_steps_len > limit {
+						Err(EngineError::TooManyEmptySteps(OutOfBounds { min: None, max: Some(limit), found: empty_steps_len }))?;
+					}
+				}
+
+				let mut previous_step = parent_step;
 				for empty_step in empty_steps {
+					// once limited, empty steps must be in the pruned order: one per step, ascending
+					if empty_steps_limit.is_some() && empty_step.step <= previous_step {
+						Err(EngineError::InsufficientProof(
+							format!("duplicate or unordered empty step proof: {:?}", empty_step.step)))?;
+					}
+					previous_step = empty_step.step;
+
 					if empty_step.step <= parent_step || empty_step.step >= step {
 						Err(EngineError::InsufficientProof(
 							format!("empty step proof for invalid step: {:?}", empty_step.step)))?;
@@ -1481,9 +1552,11 @@ mod tests {
 	use spec::Spec;
 	use transaction::{Action, Transaction};
 	use engines::{Seal, Engine, EngineError, EthEngine};
-	use engines::validator_set::TestSet;
+	use engines::validator_set::{TestSet, SimpleList};
 	use error::{Error, ErrorKind};
-	use super::{AuthorityRoundParams, AuthorityRound, EmptyStep, SealedEmptyStep, calculate_score};
+	use unexpected::OutOfBounds;
+	use super::{AuthorityRoundParams, AuthorityRound, EmptyStep, SealedEmptyStep, calculate_score,
+		header_empty_steps, prune_empty_steps};
 
 	#[test]
 	fn has_valid_metadata() {
@@ -1669,6 +1742,8 @@ mod tests {
 			maximum_uncle_count: 0,
 			empty_steps_transition: u64::max_value(),
 			maximum_empty_steps: 0,
+			empty_steps_limit_transition: u64::max_value(),
+			maximum_empty_steps_per_block: None,
 			block_reward: Default::default(),
 			block_reward_contract_transition: 0,
 			block_reward_contract: Default::default(),
@@ -1720,6 +1795,8 @@ mod tests {
 			maximum_uncle_count: 0,
 			empty_steps_transition: u64::max_value(),
 			maximum_empty_steps: 0,
+			empty_steps_limit_transition: u64::max_value(),
+			maximum_empty_steps_per_block: None,
 			block_reward: Default::default(),
 			block_reward_contract_transition: 0,
 			block_reward_contract: Default::default(),
@@ -1776,6 +1853,8 @@ mod tests {
 			maximum_uncle_count: 0,
 			empty_steps_transition: u64::max_value(),
 			maximum_empty_steps: 0,
+			empty_steps_limit_transition: u64::max_value(),
+			maximum_empty_steps_per_block: None,
 			block_reward: Default::default(),
 			block_reward_contract_transition: 0,
 			block_reward_contract: Default::default(),
@@ -1896,6 +1975,61 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn seal_with_duplicate_empty_steps() {
+		let (spec, tap, accounts) = setup_empty_steps();
+
+		let addr1 = accounts[0];
+		let addr2 = accounts[1];
+
+		let engine = &*spec.engine;
+		let genesis_header = spec.genesis_header();
+		let db1 = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+		let db2 = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+
+		let last_hashes = Arc::new(vec![genesis_header.hash()]);
+
+		let client = generate_dummy_client_with_spec_and_accounts(Spec::new_test_round_empty_steps, None);
+		let notify = Arc::new(TestNotify::default());
+		client.add_notify(notify.clone());
+		engine.register_client(Arc::downgrade(&client) as _);
+
+		// step 2
+		let b1 = OpenBlock::new(engine, Default::default(), false, db1, &genesis_header, last_hashes.clone(), addr1, (3141562.into(), 31415620.into()), vec![], false, &mut Vec::new().into_iter()).unwrap();
+		let b1 = b1.close_and_lock().unwrap();
+
+		// generate an empty step and receive it back from the network
+		engine.set_signer(tap.clone(), addr1, "1".into());
+		assert_eq!(engine.generate_seal(b1.block(), &genesis_header), Seal::None);
+		let empty_step_rlp = encode(&empty_step(engine, 2, &genesis_header.hash()));
+		engine.handle_message(&empty_step_rlp).unwrap();
+		engine.handle_message(&empty_step_rlp).unwrap();
+		engine.step();
+
+		// step 3
+		let mut b2 = OpenBlock::new(engine, Default::default(), false, db2, &genesis_header, last_hashes.clone(), addr2, (3141562.into(), 31415620.into()), vec![], false, &mut Vec::new().into_iter()).unwrap();
+		b2.push_transaction(Transaction {
+			action: Action::Create,
+			nonce: U256::from(0),
+			gas_price: U256::from(3000),
+			gas: U256::from(53_000),
+			value: U256::from(1),
+			data: vec![],
+		}.fake_sign(addr2), None).unwrap();
+		let b2 = b2.close_and_lock().unwrap();
+
+		// the empty step is only included once
+		engine.set_signer(tap.clone(), addr2, "0".into());
+		match engine.generate_seal(b2.block(), &genesis_header) {
+			Seal::Regular(seal) => {
+				engine.set_signer(tap.clone(), addr1, "1".into());
+				let empty_step2 = sealed_empty_step(engine, 2, &genesis_header.hash());
+				assert_eq!(seal[2], ::rlp::encode_list(&vec![empty_step2]));
+			},
+			_ => panic!("block should be sealed"),
+		}
+	}
+
 	#[test]
 	fn seal_empty_block_with_empty_steps() {
 		let (spec, tap, accounts) = setup_empty_steps();
@@ -2136,6 +2270,176 @@ mod tests {
 		)
 	}
 
+	fn aura_with_empty_steps_limit(
+		validators: Vec<Address>,
+		maximum_empty_steps: usize,
+		empty_steps_limit_transition: u64,
+		maximum_empty_steps_per_block: Option<usize>,
+	) -> Arc<AuthorityRound> {
+		let params = AuthorityRoundParams {
+			step_duration: 1,
+			start_step: Some(1),
+			validators: Box::new(SimpleList::new(validators)),
+			validate_score_transition: 0,
+			validate_step_transition: 0,
+			immediate_transitions: true,
+			maximum_uncle_count_transition: 0,
+			maximum_uncle_count: 0,
+			empty_steps_transition: 1,
+			maximum_empty_steps,
+			empty_steps_limit_transition,
+			maximum_empty_steps_per_block,
+			block_reward: Default::default(),
+			block_reward_contract_transition: 0,
+			block_reward_contract: Default::default(),
+		};
+
+		let machine = ::machine::EthereumMachine::regular(Spec::new_test_round_empty_steps().params().clone(), Default::default());
+		AuthorityRound::new(params, machine).unwrap()
+	}
+
+	// Every validator idles on its step, broadcasting an empty step message unless it can seal.
+	// All blocks are built on the genesis block; returns the number of empty steps in each sealed block.
+	fn seal_idle_steps(engine: &Arc<AuthorityRound>, tap: &Arc<AccountProvider>, validators: &[Address], steps: usize) -> Vec<usize> {
+		let spec = Spec::new_test_round_empty_steps();
+		let genesis_header = spec.genesis_header();
+		let last_hashes = Arc::new(vec![genesis_header.hash()]);
+
+		let client = generate_dummy_client_with_spec_and_accounts(Spec::new_test_round_empty_steps, None);
+		engine.register_client(Arc::downgrade(&client) as _);
+
+		let mut sealed = Vec::new();
+		for _ in 0..steps {
+			engine.step();
+			let step = engine.step.inner.load();
+			let proposer = validators[step % validators.len()];
+			engine.set_signer(tap.clone(), proposer, "".into());
+
+			let db = spec.ensure_db_good(get_temp_state_db(), &Default::default()).unwrap();
+			let b = OpenBlock::new(&**engine, Default::default(), false, db, &genesis_header, last_hashes.clone(), proposer, (3141562.into(), 31415620.into()), vec![], false, &mut Vec::new().into_iter()).unwrap();
+			let b = b.close_and_lock().unwrap();
+
+			if let Seal::Regular(seal) = engine.generate_seal(b.block(), &genesis_header) {
+				let mut header = b.block().header().clone();
+				header.set_seal(seal);
+				engine.verify_block_family(&header, &genesis_header).unwrap();
+				let empty_steps = header_empty_steps(&header).unwrap().len();
+				assert_eq!(engine.empty_steps_count(&header), empty_steps);
+				sealed.push(empty_steps);
+			}
+		}
+
+		sealed
+	}
+
+	fn idle_validators(count: usize) -> (Arc<AccountProvider>, Vec<Address>) {
+		let tap = Arc::new(AccountProvider::transient_provider());
+		let validators = (0..count)
+			.map(|i| tap.insert_account(keccak(format!("validator{}", i)).into(), &"".into()).unwrap())
+			.collect();
+		(tap, validators)
+	}
+
+	#[test]
+	fn idle_validators_do_not_exceed_default_empty_steps_limit() {
+		let (tap, validators) = idle_validators(100);
+		let engine = aura_with_empty_steps_limit(validators.clone(), 100, 0, None);
+
+		// 100 idle steps accumulate 100 empty steps, the block sealed after them is within the limit of 200
+		let sealed = seal_idle_steps(&engine, &tap, &validators, 101);
+		assert_eq!(sealed, vec![100]);
+	}
+
+	#[test]
+	fn idle_validators_do_not_exceed_empty_steps_limit() {
+		let (tap, validators) = idle_validators(100);
+		let engine = aura_with_empty_steps_limit(validators.clone(), 100, 0, Some(30));
+
+		// once 30 empty steps are collected every proposer seals instead of idling
+		let sealed = seal_idle_steps(&engine, &tap, &validators, 101);
+		assert_eq!(sealed.len(), 71);
+		assert!(sealed.iter().all(|&len| len == 30));
+	}
+
+	#[test]
+	fn prunes_empty_steps() {
+		let (spec, tap, accounts) = setup_empty_steps();
+		let engine = &*spec.engine;
+		let parent_hash = H256::default();
+
+		engine.set_signer(tap.clone(), accounts[0], "1".into());
+		let empty_step2 = empty_step(engine, 2, &parent_hash);
+		let empty_step4 = empty_step(engine, 4, &parent_hash);
+		engine.set_signer(tap.clone(), accounts[1], "0".into());
+		let empty_step3 = empty_step(engine, 3, &parent_hash);
+
+		let empty_steps = vec![empty_step4, empty_step2.clone(), empty_step3, empty_step2];
+		let steps = |empty_steps: Vec<EmptyStep>| empty_steps.into_iter().map(|e| e.step).collect::<Vec<_>>();
+		assert_eq!(steps(prune_empty_steps(empty_steps.clone(), None)), vec![2, 3, 4]);
+		assert_eq!(steps(prune_empty_steps(empty_steps, Some(2))), vec![2, 3]);
+	}
+
+	#[test]
+	fn verify_empty_steps_limit() {
+		let (_, tap, accounts) = setup_empty_steps();
+		let addr1 = accounts[0];
+		let addr2 = accounts[1];
+
+		let mut parent_header: Header = Header::default();
+		parent_header.set_seal(vec![encode(&0usize)]);
+		parent_header.set_gas_limit("222222".parse::<U256>().unwrap());
+
+		let mut header: Header = Header::default();
+		header.set_parent_hash(parent_header.hash());
+		header.set_number(1);
+		header.set_gas_limit("222222".parse::<U256>().unwrap());
+		header.set_author(addr1);
+		header.set_difficulty(calculate_score(U256::from(0), U256::from(4), U256::from(2)));
+
+		let seal = |engine: &AuthorityRound, empty_steps: Vec<SealedEmptyStep>| {
+			let signature = tap.sign(addr1, Some("1".into()), header.bare_hash()).unwrap();
+			let mut header = header.clone();
+			header.set_seal(vec![
+				encode(&4usize),
+				encode(&(&*signature as &[u8])),
+				::rlp::encode_list(&empty_steps),
+			]);
+			engine.verify_block_family(&header, &parent_header)
+		};
+
+		let before = aura_with_empty_steps_limit(accounts.clone(), 2, 2, Some(1));
+		let after = aura_with_empty_steps_limit(accounts.clone(), 2, 1, Some(1));
+
+		before.set_signer(tap.clone(), addr1, "1".into());
+		let empty_step2 = sealed_empty_step(&*before, 2, &parent_header.hash());
+		before.set_signer(tap.clone(), addr2, "0".into());
+		let empty_step3 = sealed_empty_step(&*before, 3, &parent_header.hash());
+
+		// more empty steps than the limit
+		assert!(seal(&*before, vec![empty_step2.clone(), empty_step3.clone()]).is_ok());
+		assert!(match seal(&*after, vec![empty_step2.clone(), empty_step3.clone()]) {
+			Err(Error(ErrorKind::Engine(EngineError::TooManyEmptySteps(OutOfBounds { max: Some(1), found: 2, .. })), _)) => true,
+			_ => false,
+		});
+
+		// duplicate empty steps
+		let after = aura_with_empty_steps_limit(accounts.clone(), 2, 1, None);
+		assert!(seal(&*before, vec![empty_step2.clone(), empty_step2.clone()]).is_ok());
+		assert!(match seal(&*after, vec![empty_step2.clone(), empty_step2.clone()]) {
+			Err(Error(ErrorKind::Engine(EngineError::InsufficientProof(ref s)), _))
+				if s.contains("duplicate or unordered empty step") => true,
+			_ => false,
+		});
+
+		// unordered empty steps
+		assert!(seal(&*before, vec![empty_step3.clone(), empty_step2.clone()]).is_ok());
+		assert!(match seal(&*after, vec![empty_step3, empty_step2]) {
+			Err(Error(ErrorKind::Engine(EngineError::InsufficientProof(ref s)), _))
+				if s.contains("duplicate or unordered empty step") => true,
+			_ => false,
+		});
+	}
+
 	#[test]
 	fn extra_info_from_seal() {
 		let (spec, tap, accounts) = setup_empty_steps();
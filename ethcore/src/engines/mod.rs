@@ -92,6 +92,8 @@ pub enum EngineError {
 	MalformedMessage(String),
 	/// Requires client ref, but none registered.
 	RequiresClient,
+	/// Block seal holds more empty step messages than allowed.
+	TooManyEmptySteps(OutOfBounds<usize>),
 }
 
 impl fmt::Display for EngineError {
@@ -107,6 +109,7 @@ impl fmt::Display for EngineError {
 			FailedSystemCall(ref msg) => format!("Failed to make system call: {}", msg),
 			MalformedMessage(ref msg) => format!("Received malformed consensus message: {}", msg),
 			RequiresClient => format!("Call requires client but none registered"),
+			TooManyEmptySteps(ref oob) => format!("Too many empty steps in the seal: {}", oob),
 		};
 
 		f.write_fmt(format_args!("Engine error ({})", msg))
@@ -248,6 +251,9 @@ pub trait Engine<M: Machine>: Sync + Send {
 	/// Additional engine-specific information for the user/developer concerning `header`.
 	fn extra_info(&self, _header: &M::Header) -> BTreeMap<String, String> { BTreeMap::new() }
 
+	/// Number of empty step messages sealed in `header`.
+	fn empty_steps_count(&self, _header: &M::Header) -> usize { 0 }
+
 	/// Maximum number of uncles a block is allowed to declare.
 	fn maximum_uncle_count(&self, _block: BlockNumber) -> usize { 0 }
 
@@ -167,7 +167,11 @@ pub enum CallError {
 	/// Couldn't find requested block's state in the chain.
 	StatePruned,
 	/// Couldn't find an amount of gas that didn't result in an exception.
-	Exceptional,
+	Exceptional(vm::Error),
+	/// Couldn't find an amount of gas that didn't result in a revert. Contains the revert output.
+	Reverted(Bytes),
+	/// Couldn't find an amount of gas up to the given upper limit that didn't run out of gas.
+	ExceedsGasLimit(U256),
 	/// Corrupt state.
 	StateCorrupt,
 	/// Error executing.
@@ -187,7 +191,9 @@ impl fmt::Display for CallError {
 		let msg = match *self {
 			TransactionNotFound => "Transaction couldn't be found in the chain".into(),
 			StatePruned => "Couldn't find the transaction block's state in the chain".into(),
-			Exceptional => "An exception happened in the execution".into(),
+			Exceptional(ref e) => format!("An exception happened in the execution: {}", e),
+			Reverted(_) => "The execution was reverted".into(),
+			ExceedsGasLimit(ref limit) => format!("Requires higher than upper limit of {}", limit),
 			StateCorrupt => "Stored state found to be corrupted.".into(),
 			Execution(ref e) => format!("{}", e),
 		};
@@ -852,6 +852,21 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
 		}
 
 		let mut substate = Substate::new();
+		self.state.clear_transient();
+
+		if schedule.eip2929 {
+			// EIP-2929: the sender, the destination and the active precompiles start warm.
+			let destination = match t.action {
+				Action::Create => contract_address(self.machine.create_address_scheme(self.info.number), &sender, &nonce, &t.data).0,
+				Action::Call(ref address) => address.clone(),
+			};
+			let number = self.info.number;
+			self.state.access_address(sender.clone());
+			self.state.access_address(destination);
+			for (address, _) in self.machine.builtins().iter().filter(|&(_, b)| b.is_active(number)) {
+				self.state.access_address(*address);
+			}
+		}
 
 		// NOTE: there can be no invalid transactions from this point.
 		if !schedule.keep_unsigned_nonce || !t.is_unsigned() {
@@ -1099,7 +1114,7 @@ impl<'a, B: 'a + StateBackend> Executive<'a, B> {
 
 		// real ammount to refund
 		let gas_left_prerefund = match result { Ok(FinalizationResult{ gas_left, .. }) => gas_left, _ => 0.into() };
-		let refunded = cmp::min(refunds_bound, (t.gas - gas_left_prerefund) >> 1);
+		let refunded = cmp::min(refunds_bound, (t.gas - gas_left_prerefund) / U256::from(schedule.max_refund_quotient));
 		let gas_left = gas_left_prerefund + refunded;
 
 		let gas_used = t.gas - gas_left;
@@ -1888,6 +1903,98 @@ mod tests {
 		assert_eq!(state.storage_at(&contract, &H256::new()).unwrap(), H256::from(&U256::from(1)));
 	}
 
+	evm_test!{test_transact_refund_cap: test_transact_refund_cap_int}
+	fn test_transact_refund_cap(factory: Factory) {
+		let keypair = Random.generate().unwrap();
+		let t = Transaction {
+			action: Action::Call(Address::from(0x1000)),
+			value: U256::zero(),
+			data: vec![],
+			gas: U256::from(100_000),
+			gas_price: U256::zero(),
+			nonce: U256::zero()
+		}.sign(keypair.secret(), None);
+		let contract = Address::from(0x1000);
+
+		let mut info = EnvInfo::default();
+		info.gas_limit = U256::from(100_000);
+		let machine = make_frontier_machine(0);
+
+		let transact = |schedule: &Schedule| {
+			let mut state = get_temp_state_with_factory(factory.clone());
+			// clear storage slots 0, 1 and 2
+			state.new_contract(&contract, U256::zero(), U256::zero()).unwrap();
+			state.init_code(&contract, "600060005560006001556000600255".from_hex().unwrap()).unwrap();
+			for key in 0..3u64 {
+				state.set_storage(&contract, H256::from(key), H256::from(1)).unwrap();
+			}
+
+			let mut ex = Executive::new(&mut state, &info, &machine, schedule);
+			ex.transact(&t, TransactOptions::with_no_tracing()).unwrap()
+		};
+
+		// 36018 gas spent, 45000 refund capped at half of it
+		let schedule = machine.schedule(info.number);
+		let executed = transact(&schedule);
+		assert_eq!(executed.gas_used, U256::from(18_009));
+
+		// EIP-3529: 14400 refund capped at a fifth of the gas spent
+		let mut schedule = machine.schedule(info.number);
+		schedule.suicide_refund_gas = 0;
+		schedule.sstore_refund_gas = 4800;
+		schedule.max_refund_quotient = 5;
+		let executed = transact(&schedule);
+		assert_eq!(executed.gas_used, U256::from(28_815));
+	}
+
+	evm_test!{test_eip3541: test_eip3541_int}
+	fn test_eip3541(factory: Factory) {
+		// init code returning a single 0xEF byte
+		let code = "60ef60005360016000f3".from_hex().unwrap();
+
+		let sender = Address::from_str("cd1722f3947def4cf144679da39c4c32bdc35681").unwrap();
+		let address = contract_address(CreateContractAddress::FromSenderAndNonce, &sender, &U256::zero(), &[]).0;
+		let mut params = ActionParams::default();
+		params.address = address.clone();
+		params.sender = sender.clone();
+		params.origin = sender.clone();
+		params.gas = U256::from(100_000);
+		params.code = Some(Arc::new(code));
+
+		let info = EnvInfo::default();
+		let machine = ::ethereum::new_constantinople_test_machine();
+		let mut schedule = machine.schedule(info.number);
+
+		let mut state = get_temp_state_with_factory(factory.clone());
+		let result = {
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.create(params.clone(), &mut Substate::new(), &mut NoopTracer, &mut NoopVMTracer)
+		};
+		assert!(result.is_ok());
+		assert_eq!(state.code(&address).unwrap().unwrap()[..], [0xef][..]);
+
+		// already deployed code is still executed
+		let mut call_params = ActionParams::default();
+		call_params.address = address.clone();
+		call_params.code_address = address.clone();
+		call_params.gas = U256::from(100_000);
+		call_params.code = state.code(&address).unwrap();
+		schedule.eip3541 = true;
+		let result = {
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.call(call_params, &mut Substate::new(), &mut NoopTracer, &mut NoopVMTracer)
+		};
+		assert_eq!(result.err(), Some(vm::Error::BadInstruction { instruction: 0xef }));
+
+		let mut state = get_temp_state_with_factory(factory);
+		let result = {
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.create(params, &mut Substate::new(), &mut NoopTracer, &mut NoopVMTracer)
+		};
+		assert_eq!(result.err(), Some(vm::Error::OutOfGas));
+		assert_eq!(state.code(&address).unwrap(), None);
+	}
+
 	evm_test!{test_transact_invalid_nonce: test_transact_invalid_nonce_int}
 	fn test_transact_invalid_nonce(factory: Factory) {
 		let keypair = Random.generate().unwrap();
@@ -2117,6 +2224,118 @@ mod tests {
 		assert_eq!(refund, 19800);
 	}
 
+	evm_test!{test_eip2929_reverted_access_list: test_eip2929_reverted_access_list_int}
+	fn test_eip2929_reverted_access_list(factory: Factory) {
+		let x = Address::from(0x1000);
+		let y = Address::from(0x1001);
+		let k = H256::new();
+
+		let mut state = get_temp_state_with_factory(factory);
+		// x: SLOAD(0) then REVERT
+		state.new_contract(&x, U256::zero(), U256::from(1)).unwrap();
+		state.init_code(&x, "6000545060006000fd".from_hex().unwrap()).unwrap();
+		// y: SLOAD(0) then STOP
+		state.new_contract(&y, U256::zero(), U256::from(1)).unwrap();
+		state.init_code(&y, "6000545000".from_hex().unwrap()).unwrap();
+
+		let info = EnvInfo::default();
+		let machine = ::ethereum::new_constantinople_test_machine();
+		let mut schedule = machine.schedule(info.number);
+		schedule.eip2929 = true;
+
+		let mut params = ActionParams::default();
+		// CALL x, CALL y
+		params.code = Some(Arc::new("6000600060006000600061100061fffff1506000600060006000600061100161fffff15000".from_hex().unwrap()));
+		params.gas = U256::from(100_000);
+		let mut substate = Substate::new();
+		{
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.call(params, &mut substate, &mut NoopTracer, &mut NoopVMTracer).unwrap();
+		}
+
+		// call targets are warmed by the caller and stay warm
+		assert!(state.is_address_accessed(&x));
+		assert!(state.is_address_accessed(&y));
+		// storage accessed by the reverted call is cold again
+		assert!(!state.is_storage_key_accessed(&x, &k));
+		assert!(state.is_storage_key_accessed(&y, &k));
+	}
+
+	evm_test!{test_eip2929_selfdestruct: test_eip2929_selfdestruct_int}
+	fn test_eip2929_selfdestruct(factory: Factory) {
+		let beneficiary = Address::from(0xff);
+
+		let mut state = get_temp_state_with_factory(factory);
+		let info = EnvInfo::default();
+		let machine = ::ethereum::new_constantinople_test_machine();
+		let mut schedule = machine.schedule(info.number);
+		schedule.eip2929 = true;
+
+		let mut gas_used = Vec::new();
+		for _ in 0..2 {
+			let gas = U256::from(100_000);
+			let mut params = ActionParams::default();
+			// SELFDESTRUCT(0xff)
+			params.code = Some(Arc::new("60ffff".from_hex().unwrap()));
+			params.gas = gas;
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			let FinalizationResult { gas_left, .. } = ex.call(params, &mut Substate::new(), &mut NoopTracer, &mut NoopVMTracer).unwrap();
+			gas_used.push(gas - gas_left);
+		}
+
+		// the beneficiary is cold (5000 + 2600) and then warm (5000)
+		assert_eq!(gas_used, vec![U256::from(7603), U256::from(5003)]);
+		assert!(state.is_address_accessed(&beneficiary));
+	}
+
+	evm_test!{test_eip1153_transient_storage: test_eip1153_transient_storage_int}
+	fn test_eip1153_transient_storage(factory: Factory) {
+		let x = Address::from(0x1000);
+		let z = Address::from(0x1002);
+		let operating_address = Address::from(0);
+
+		let mut state = get_temp_state_with_factory(factory);
+		// x: TSTORE(0, 1)
+		state.new_contract(&x, U256::zero(), U256::from(1)).unwrap();
+		state.init_code(&x, "600160005d00".from_hex().unwrap()).unwrap();
+		// z: TSTORE(1, 1) then REVERT
+		state.new_contract(&z, U256::zero(), U256::from(1)).unwrap();
+		state.init_code(&z, "600160015d60006000fd".from_hex().unwrap()).unwrap();
+
+		let info = EnvInfo::default();
+		let machine = ::ethereum::new_constantinople_test_machine();
+		let mut schedule = machine.schedule(info.number);
+		schedule.have_transient_storage = true;
+
+		// DELEGATECALL x, DELEGATECALL z, SSTORE(0, TLOAD(0)), SSTORE(1, TLOAD(1))
+		let mut params = ActionParams::default();
+		params.code = Some(Arc::new("600060006000600061100061fffff450600060006000600061100261fffff45060005c60005560015c600155".from_hex().unwrap()));
+		params.gas = U256::from(200_000);
+		let mut substate = Substate::new();
+		{
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.call(params, &mut substate, &mut NoopTracer, &mut NoopVMTracer).unwrap();
+		}
+
+		// written in a sub-call and visible to the caller
+		assert_eq!(state.storage_at(&operating_address, &H256::from(0)).unwrap(), H256::from(1));
+		// rolled back together with the reverted sub-call
+		assert_eq!(state.storage_at(&operating_address, &H256::from(1)).unwrap(), H256::from(0));
+		assert_eq!(state.transient_storage_at(&operating_address, &H256::from(0)), H256::from(1));
+		assert_eq!(state.transient_storage_at(&operating_address, &H256::from(1)), H256::from(0));
+
+		// SSTORE(2, TLOAD(0)) in a fresh transaction
+		state.clear_transient();
+		let mut params = ActionParams::default();
+		params.code = Some(Arc::new("60005c600255".from_hex().unwrap()));
+		params.gas = U256::from(100_000);
+		{
+			let mut ex = Executive::new(&mut state, &info, &machine, &schedule);
+			ex.call(params, &mut Substate::new(), &mut NoopTracer, &mut NoopVMTracer).unwrap();
+		}
+		assert_eq!(state.storage_at(&operating_address, &H256::from(2)).unwrap(), H256::from(0));
+	}
+
 	fn wasm_sample_code() -> Arc<Vec<u8>> {
 		Arc::new(
 			"0061736d01000000010d0360027f7f0060017f0060000002270303656e7603726574000003656e760673656e646572000103656e76066d656d6f727902010110030201020404017000000501000708010463616c6c00020901000ac10101be0102057f017e4100410028020441c0006b22043602042004412c6a41106a220041003602002004412c6a41086a22014200370200200441186a41106a22024100360200200441186a41086a220342003703002004420037022c2004410036021c20044100360218200441186a1001200020022802002202360200200120032903002205370200200441106a2002360200200441086a200537030020042004290318220537022c200420053703002004411410004100200441c0006a3602040b0b0a010041040b0410c00000"
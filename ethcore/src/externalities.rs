@@ -131,6 +131,19 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for Externalities<'a, T, V, B>
 		}
 	}
 
+	fn transient_storage_at(&self, key: &H256) -> vm::Result<H256> {
+		Ok(self.state.transient_storage_at(&self.origin_info.address, key))
+	}
+
+	fn set_transient_storage(&mut self, key: H256, value: H256) -> vm::Result<()> {
+		if self.static_flag {
+			Err(vm::Error::MutableCallInStaticContext)
+		} else {
+			self.state.set_transient_storage(self.origin_info.address, key, value);
+			Ok(())
+		}
+	}
+
 	fn is_static(&self) -> bool {
 		return self.static_flag
 	}
@@ -220,6 +233,11 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for Externalities<'a, T, V, B>
 			}
 		};
 
+		if self.schedule.eip2929 {
+			// the created address is warm even if the creation fails
+			self.al_insert_address(address.clone());
+		}
+
 		// prepare the params
 		let params = ActionParams {
 			code_address: address.clone(),
@@ -323,6 +341,10 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for Externalities<'a, T, V, B>
 				Ok(*gas)
 			},
 			OutputPolicy::InitContract if apply_state => {
+				if self.schedule.eip3541 && data.first() == Some(&0xef) {
+					// EIP-3541: the 0xEF prefix is reserved
+					return Err(vm::Error::OutOfGas);
+				}
 				let return_cost = U256::from(data.len()) * U256::from(self.schedule.create_data_gas);
 				if return_cost > *gas || data.len() > self.schedule.create_data_limit {
 					return match self.schedule.exceptional_failed_code_deposit {
@@ -402,10 +424,30 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for Externalities<'a, T, V, B>
 		self.substate.sstore_clears_refund -= value as i128;
 	}
 
+	fn al_contains_address(&self, address: &Address) -> bool {
+		self.state.is_address_accessed(address)
+	}
+
+	fn al_insert_address(&mut self, address: Address) {
+		self.state.access_address(address)
+	}
+
+	fn al_contains_storage_key(&self, key: &H256) -> bool {
+		self.state.is_storage_key_accessed(&self.origin_info.address, key)
+	}
+
+	fn al_insert_storage_key(&mut self, key: H256) {
+		self.state.access_storage_key(self.origin_info.address, key)
+	}
+
 	fn trace_next_instruction(&mut self, pc: usize, instruction: u8, current_gas: U256) -> bool {
 		self.vm_tracer.trace_next_instruction(pc, instruction, current_gas)
 	}
 
+	fn trace_step(&mut self, pc: usize, instruction: u8, current_gas: U256, stack: &[U256]) {
+		self.vm_tracer.trace_step(&self.origin_info.address, pc, instruction, current_gas, stack)
+	}
+
 	fn trace_prepare_execute(&mut self, pc: usize, instruction: u8, gas_cost: U256, mem_written: Option<(usize, usize)>, store_written: Option<(U256, U256)>) {
 		self.vm_tracer.trace_prepare_execute(pc, instruction, gas_cost, mem_written, store_written)
 	}
@@ -588,6 +630,23 @@ mod tests {
 		assert_eq!(setup.sub_state.suicides.len(), 1);
 	}
 
+	#[test]
+	fn can_not_set_transient_storage_in_static_context() {
+		let key = H256::from(1);
+
+		let mut setup = TestSetup::new();
+		let state = &mut setup.state;
+		let mut tracer = NoopTracer;
+		let mut vm_tracer = NoopVMTracer;
+		let origin_info = get_test_origin();
+
+		{
+			let mut ext = Externalities::new(state, &setup.env_info, &setup.machine, &setup.schedule, 0, 0, &origin_info, &mut setup.sub_state, OutputPolicy::InitContract, &mut tracer, &mut vm_tracer, true);
+			assert_eq!(ext.set_transient_storage(key, H256::from(2)), Err(vm::Error::MutableCallInStaticContext));
+			assert_eq!(ext.transient_storage_at(&key).unwrap(), H256::zero());
+		}
+	}
+
 	#[test]
 	fn can_create() {
 		use std::str::FromStr;
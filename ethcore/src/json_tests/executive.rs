@@ -120,6 +120,14 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for TestExt<'a, T, V, B>
 		self.ext.set_storage(key, value)
 	}
 
+	fn transient_storage_at(&self, key: &H256) -> vm::Result<H256> {
+		self.ext.transient_storage_at(key)
+	}
+
+	fn set_transient_storage(&mut self, key: H256, value: H256) -> vm::Result<()> {
+		self.ext.set_transient_storage(key, value)
+	}
+
 	fn exists(&self, address: &Address) -> vm::Result<bool> {
 		self.ext.exists(address)
 	}
@@ -225,6 +233,22 @@ impl<'a, T: 'a, V: 'a, B: 'a> Ext for TestExt<'a, T, V, B>
 	fn sub_sstore_refund(&mut self, value: usize) {
 		self.ext.sub_sstore_refund(value)
 	}
+
+	fn al_contains_address(&self, address: &Address) -> bool {
+		self.ext.al_contains_address(address)
+	}
+
+	fn al_insert_address(&mut self, address: Address) {
+		self.ext.al_insert_address(address)
+	}
+
+	fn al_contains_storage_key(&self, key: &H256) -> bool {
+		self.ext.al_contains_storage_key(key)
+	}
+
+	fn al_insert_storage_key(&mut self, key: H256) {
+		self.ext.al_insert_storage_key(key)
+	}
 }
 
 fn do_json_test<H: FnMut(&str, HookType)>(json_data: &[u8], h: &mut H) -> Vec<String> {
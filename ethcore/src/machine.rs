@@ -163,6 +163,8 @@ impl EthereumMachine {
 		};
 
 		let mut state = block.state_mut();
+		// the system call must not see what the last transaction accessed
+		state.clear_transient();
 
 		let params = ActionParams {
 			code_address: contract_address.unwrap_or(UNSIGNED_SENDER),
@@ -514,6 +516,76 @@ mod tests {
 		}
 	}
 
+	fn open_test_block<'a>(spec: &'a ::spec::Spec) -> ::block::OpenBlock<'a> {
+		let genesis_header = spec.genesis_header();
+		let db = spec.ensure_db_good(::test_helpers::get_temp_state_db(), &Default::default()).unwrap();
+		let last_hashes = Arc::new(vec![genesis_header.hash()]);
+		::block::OpenBlock::new(&*spec.engine, Default::default(), false, db, &genesis_header, last_hashes, Address::zero(), (3141562.into(), 31415620.into()), vec![], false, &mut Vec::new().into_iter()).unwrap()
+	}
+
+	#[test]
+	fn system_call_does_not_inherit_accessed_addresses() {
+		let spec = ::spec::Spec::new_test();
+		let mut params = spec.params().clone();
+		params.eip2929_transition = 0;
+		let machine = EthereumMachine::regular(params, Default::default());
+		let mut b = open_test_block(&spec);
+		let contract = Address::from(0x1000);
+		let touched = Address::from(0x2000);
+
+		// left warm by the last transaction of the block
+		b.block_mut().state_mut().access_address(touched);
+
+		// SSTORE(0, gas spent on PUSH2 0x2000, BALANCE, POP, GAS)
+		let code = ::rustc_hex::FromHex::from_hex("5a61200031505a900360005500").unwrap();
+		machine.execute_code_as_system(b.block_mut(), Some(contract), Some(Arc::new(code)), None, None, 100_000.into(), None, None).unwrap();
+
+		// BALANCE is charged for a cold address
+		assert_eq!(b.block().state.storage_at(&contract, &H256::zero()).unwrap(), H256::from(2607));
+	}
+
+	#[test]
+	fn system_call_does_not_inherit_transient_storage() {
+		use ethkey::{Generator, Random};
+		use executive::TransactOptions;
+		use transaction::{Action, Transaction};
+
+		let spec = ::spec::Spec::new_test();
+		let mut params = spec.params().clone();
+		params.eip1153_transition = 0;
+		let machine = EthereumMachine::regular(params, Default::default());
+		let mut b = open_test_block(&spec);
+		let contract = Address::from(0x1000);
+
+		// TSTORE(0, 1)
+		let code = ::rustc_hex::FromHex::from_hex("600160005d00").unwrap();
+		b.block_mut().state_mut().init_code(&contract, code).unwrap();
+
+		let keypair = Random.generate().unwrap();
+		let t = Transaction {
+			action: Action::Call(contract),
+			value: U256::zero(),
+			data: vec![],
+			gas: U256::from(100_000),
+			gas_price: U256::zero(),
+			nonce: U256::zero(),
+		}.sign(keypair.secret(), None);
+		let env_info = b.block().env_info();
+		let schedule = machine.schedule(env_info.number);
+		{
+			let mut ex = Executive::new(b.block_mut().state_mut(), &env_info, &machine, &schedule);
+			ex.transact(&t, TransactOptions::with_no_tracing()).unwrap();
+		}
+		assert_eq!(b.block().state.transient_storage_at(&contract, &H256::zero()), H256::from(1));
+
+		// SSTORE(0, ISZERO(TLOAD(0)))
+		let code = ::rustc_hex::FromHex::from_hex("60005c1560005500").unwrap();
+		machine.execute_code_as_system(b.block_mut(), Some(contract), Some(Arc::new(code)), None, None, 100_000.into(), None, None).unwrap();
+
+		// TLOAD in the system call reads zero
+		assert_eq!(b.block().state.storage_at(&contract, &H256::zero()).unwrap(), H256::from(1));
+	}
+
 	#[test]
 	fn should_disallow_unsigned_transactions() {
 		let rlp = "ea80843b9aca0083015f90948921ebb5f79e9e3920abe571004d0b1d5119c154865af3107a400080038080".into();
@@ -584,4 +656,45 @@ mod tests {
 		machine.populate_from_parent(&mut header, &parent, U256::from(150_000), U256::from(150_002));
 		assert_eq!(*header.gas_limit(), U256::from(150_002));
 	}
+
+	#[test]
+	fn ethash_gas_limit_converges_to_target() {
+		let spec = ::ethereum::new_homestead_test();
+		let ethparams = get_default_ethash_extensions();
+
+		let machine = EthereumMachine::with_ethash_extensions(
+			spec.params().clone(),
+			Default::default(),
+			ethparams,
+		);
+
+		// --gas-floor-target and --gas-cap both set to the target
+		let target = U256::from(30_000_000);
+		let mut parent = ::header::Header::new();
+		parent.set_gas_limit(U256::from(8_000_000));
+
+		let mut blocks = 0;
+		while *parent.gas_limit() + PARITY_GAS_LIMIT_DETERMINANT <= target {
+			assert!(blocks < 22_000, "gas limit should reach the target within 22000 blocks");
+			let mut header = ::header::Header::new();
+			header.set_number(parent.number() + 1);
+			machine.populate_from_parent(&mut header, &parent, target, target);
+
+			// moves up by at most 1/1024 of the parent's limit per block
+			assert!(*header.gas_limit() > *parent.gas_limit());
+			assert!(*header.gas_limit() - *parent.gas_limit() < *parent.gas_limit() / U256::from(1024));
+			parent = header;
+			blocks += 1;
+		}
+
+		// and then stays within rounding distance of it
+		for _ in 0..100 {
+			let mut header = ::header::Header::new();
+			header.set_number(parent.number() + 1);
+			machine.populate_from_parent(&mut header, &parent, target, target);
+			assert!(*header.gas_limit() + PARITY_GAS_LIMIT_DETERMINANT > target);
+			assert!(*header.gas_limit() < target + PARITY_GAS_LIMIT_DETERMINANT);
+			parent = header;
+		}
+	}
 }
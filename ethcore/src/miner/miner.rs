@@ -138,6 +138,8 @@ pub struct MinerOptions {
 
 	/// Strategy to use for prioritizing transactions in the queue.
 	pub tx_queue_strategy: PrioritizationStrategy,
+	/// Minimal gas price increase (in percent) required to replace a transaction in the queue.
+	pub tx_queue_bump_percent: usize,
 	/// Simple senders penalization.
 	pub tx_queue_penalization: Penalization,
 	/// Do we want to mark transactions recieved locally (e.g. RPC) as local if we don't have the sending account?
@@ -164,6 +166,7 @@ impl Default for MinerOptions {
 			enable_resubmission: true,
 			infinite_pending_block: false,
 			tx_queue_strategy: PrioritizationStrategy::GasPriceOnly,
+			tx_queue_bump_percent: pool::scoring::DEFAULT_GAS_PRICE_BUMP_PERCENT,
 			tx_queue_penalization: Penalization::Disabled,
 			tx_queue_no_unfamiliar_locals: false,
 			refuse_service_transactions: false,
@@ -249,7 +252,10 @@ impl Miner {
 	) -> Self {
 		let limits = options.pool_limits.clone();
 		let verifier_options = options.pool_verification_options.clone();
-		let tx_queue_strategy = options.tx_queue_strategy;
+		let tx_queue_scoring = pool::scoring::NonceAndGasPrice {
+			strategy: options.tx_queue_strategy,
+			bump_percent: options.tx_queue_bump_percent,
+		};
 		let nonce_cache_size = cmp::max(4096, limits.max_count / 4);
 
 		Miner {
@@ -267,7 +273,7 @@ impl Miner {
 			gas_pricer: Mutex::new(gas_pricer),
 			nonce_cache: NonceCache::new(nonce_cache_size),
 			options,
-			transaction_queue: Arc::new(TransactionQueue::new(limits, verifier_options, tx_queue_strategy)),
+			transaction_queue: Arc::new(TransactionQueue::with_scoring(limits, verifier_options, tx_queue_scoring)),
 			accounts,
 			engine: spec.engine.clone(),
 			io_channel: RwLock::new(None),
@@ -1337,6 +1343,7 @@ mod tests {
 				infinite_pending_block: false,
 				tx_queue_penalization: Penalization::Disabled,
 				tx_queue_strategy: PrioritizationStrategy::GasPriceOnly,
+				tx_queue_bump_percent: pool::scoring::DEFAULT_GAS_PRICE_BUMP_PERCENT,
 				tx_queue_no_unfamiliar_locals: false,
 				refuse_service_transactions: false,
 				pool_limits: Default::default(),
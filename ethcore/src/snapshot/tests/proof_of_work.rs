@@ -51,6 +51,7 @@ fn chunk_and_restore(amount: u64) {
 		bc.insert_block(&mut batch, block.encoded(), vec![], ExtrasInsert {
 			fork_choice: ::engines::ForkChoice::New,
 			is_finalized: false,
+			empty_steps: 0,
 		});
 		bc.commit();
 	}
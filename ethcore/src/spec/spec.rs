@@ -120,6 +120,18 @@ pub struct CommonParams {
 	pub eip1052_transition: BlockNumber,
 	/// Number of first block where EIP-1283 rules begin.
 	pub eip1283_transition: BlockNumber,
+	/// Number of first block where EIP-1153 rules begin.
+	pub eip1153_transition: BlockNumber,
+	/// Number of first block where EIP-3855 rules begin.
+	pub eip3855_transition: BlockNumber,
+	/// Number of first block where EIP-5656 rules begin.
+	pub eip5656_transition: BlockNumber,
+	/// Number of first block where EIP-2929 rules begin.
+	pub eip2929_transition: BlockNumber,
+	/// Number of first block where EIP-3529 rules begin.
+	pub eip3529_transition: BlockNumber,
+	/// Number of first block where EIP-3541 rules begin.
+	pub eip3541_transition: BlockNumber,
 	/// Number of first block where EIP-1014 rules begin.
 	pub eip1014_transition: BlockNumber,
 	/// Number of first block where dust cleanup rules (EIP-168 and EIP169) begin.
@@ -188,7 +200,21 @@ impl CommonParams {
 		schedule.have_return_data = block_number >= self.eip211_transition;
 		schedule.have_bitwise_shifting = block_number >= self.eip145_transition;
 		schedule.have_extcodehash = block_number >= self.eip1052_transition;
+		schedule.have_transient_storage = block_number >= self.eip1153_transition;
+		schedule.have_push0 = block_number >= self.eip3855_transition;
+		schedule.have_mcopy = block_number >= self.eip5656_transition;
 		schedule.eip1283 = block_number >= self.eip1283_transition;
+		schedule.eip3541 = block_number >= self.eip3541_transition;
+		if block_number >= self.eip2929_transition {
+			schedule.eip2929 = true;
+			schedule.sload_gas = schedule.warm_storage_read_cost;
+			schedule.sstore_reset_gas -= schedule.cold_sload_cost;
+		}
+		if block_number >= self.eip3529_transition {
+			schedule.suicide_refund_gas = 0;
+			schedule.sstore_refund_gas = 4800;
+			schedule.max_refund_quotient = 5;
+		}
 		if block_number >= self.eip210_transition {
 			schedule.blockhash_gas = 800;
 		}
@@ -296,6 +322,30 @@ impl From<ethjson::spec::Params> for CommonParams {
 				BlockNumber::max_value,
 				Into::into,
 			),
+			eip1153_transition: p.eip1153_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip3855_transition: p.eip3855_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip5656_transition: p.eip5656_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip2929_transition: p.eip2929_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip3529_transition: p.eip3529_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
+			eip3541_transition: p.eip3541_transition.map_or_else(
+				BlockNumber::max_value,
+				Into::into,
+			),
 			eip1014_transition: p.eip1014_transition.map_or_else(
 				BlockNumber::max_value,
 				Into::into,
@@ -26,12 +26,13 @@ use hashdb::HashDB;
 use keccak_hasher::KeccakHasher;
 use kvdb::DBValue;
 use bytes::{Bytes, ToPretty};
-use trie::{Trie, Recorder};
+use trie::Trie;
 use ethtrie::{TrieFactory, TrieDB, SecTrieDB, Result as TrieResult};
 use pod_account::*;
 use rlp::{RlpStream, encode};
 use lru_cache::LruCache;
 use basic_account::BasicAccount;
+use super::proof::generate_proof_with;
 
 use std::cell::{RefCell, Cell};
 
@@ -580,16 +581,11 @@ impl Account {
 	/// `storage_key` is the hash of the desired storage key, meaning
 	/// this will only work correctly under a secure trie.
 	pub fn prove_storage(&self, db: &HashDB<KeccakHasher, DBValue>, storage_key: H256) -> TrieResult<(Vec<Bytes>, H256)> {
-		let mut recorder = Recorder::new();
-
 		let trie = TrieDB::new(db, &self.storage_root)?;
-		let item: U256 = {
-			let panicky_decoder = |bytes:&[u8]| ::rlp::decode(bytes).expect("decoding db value failed");
-			let query = (&mut recorder, panicky_decoder);
-			trie.get_with(&storage_key, query)?.unwrap_or_else(U256::zero)
-		};
+		let panicky_decoder = |bytes: &[u8]| -> U256 { ::rlp::decode(bytes).expect("decoding db value failed") };
+		let (proof, item) = generate_proof_with(&trie, &storage_key, panicky_decoder)?;
 
-		Ok((recorder.drain().into_iter().map(|r| r.data).collect(), item.into()))
+		Ok((proof, item.unwrap_or_else(U256::zero).into()))
 	}
 }
 
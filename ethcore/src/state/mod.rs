@@ -48,18 +48,23 @@ use keccak_hasher::KeccakHasher;
 use kvdb::DBValue;
 use bytes::Bytes;
 
-use trie::{Trie, TrieError, Recorder};
+use trie::{Trie, TrieError};
 use ethtrie::{TrieDB, Result as TrieResult};
 
 mod account;
+mod proof;
 mod substate;
+mod transient;
 
 pub mod backend;
 
 pub use self::account::Account;
 pub use self::backend::Backend;
+pub use self::proof::{generate_proof, verify_proof};
 pub use self::substate::Substate;
 
+use self::transient::TransientState;
+
 /// Used to return information about an `State::apply` operation.
 pub struct ApplyOutcome<T, V> {
 	/// The receipt for the applied transaction.
@@ -306,12 +311,17 @@ pub fn prove_transaction_virtual<H: AsHashDB<KeccakHasher, DBValue> + Send + Syn
 /// checkpoint can be discarded with `discard_checkpoint`. All of the orignal
 /// backed-up values are moved into a parent checkpoint (if any).
 ///
+/// The transaction-scoped `TransientState` (EIP-2929 access list and EIP-1153
+/// transient storage) follows the same checkpoints and is cleared at the start
+/// of every transaction.
+///
 pub struct State<B> {
 	db: B,
 	root: H256,
 	cache: RefCell<HashMap<Address, AccountEntry>>,
 	// The original account is preserved in
 	checkpoints: RefCell<Vec<HashMap<Address, Option<AccountEntry>>>>,
+	transient: TransientState,
 	account_start_nonce: U256,
 	factories: Factories,
 }
@@ -374,6 +384,7 @@ impl<B: Backend> State<B> {
 			root: root,
 			cache: RefCell::new(HashMap::new()),
 			checkpoints: RefCell::new(Vec::new()),
+			transient: TransientState::default(),
 			account_start_nonce: account_start_nonce,
 			factories: factories,
 		}
@@ -390,6 +401,7 @@ impl<B: Backend> State<B> {
 			root: root,
 			cache: RefCell::new(HashMap::new()),
 			checkpoints: RefCell::new(Vec::new()),
+			transient: TransientState::default(),
 			account_start_nonce: account_start_nonce,
 			factories: factories
 		};
@@ -407,11 +419,13 @@ impl<B: Backend> State<B> {
 		let checkpoints = self.checkpoints.get_mut();
 		let index = checkpoints.len();
 		checkpoints.push(HashMap::new());
+		self.transient.checkpoint();
 		index
 	}
 
 	/// Merge last checkpoint with previous.
 	pub fn discard_checkpoint(&mut self) {
+		self.transient.discard_checkpoint();
 		// merge with previous checkpoint
 		let last = self.checkpoints.get_mut().pop();
 		if let Some(mut checkpoint) = last {
@@ -429,6 +443,7 @@ impl<B: Backend> State<B> {
 
 	/// Revert to the last checkpoint and discard it.
 	pub fn revert_to_checkpoint(&mut self) {
+		self.transient.revert_to_checkpoint();
 		if let Some(mut checkpoint) = self.checkpoints.get_mut().pop() {
 			for (k, v) in checkpoint.drain() {
 				match v {
@@ -456,6 +471,41 @@ impl<B: Backend> State<B> {
 		}
 	}
 
+	/// Forget the transaction-scoped state of the previous transaction.
+	pub fn clear_transient(&mut self) {
+		self.transient.clear();
+	}
+
+	/// Check if the address has been accessed in the current transaction (EIP-2929).
+	pub fn is_address_accessed(&self, address: &Address) -> bool {
+		self.transient.is_address_accessed(address)
+	}
+
+	/// Mark the address as accessed in the current transaction (EIP-2929).
+	pub fn access_address(&mut self, address: Address) {
+		self.transient.access_address(address)
+	}
+
+	/// Check if the storage key of `address` has been accessed in the current transaction (EIP-2929).
+	pub fn is_storage_key_accessed(&self, address: &Address, key: &H256) -> bool {
+		self.transient.is_storage_key_accessed(address, key)
+	}
+
+	/// Mark the storage key of `address` as accessed in the current transaction (EIP-2929).
+	pub fn access_storage_key(&mut self, address: Address, key: H256) {
+		self.transient.access_storage_key(address, key)
+	}
+
+	/// Get the transient storage value of `address` for `key` (EIP-1153).
+	pub fn transient_storage_at(&self, address: &Address, key: &H256) -> H256 {
+		self.transient.transient_storage_at(address, key)
+	}
+
+	/// Set the transient storage value of `address` for `key` (EIP-1153).
+	pub fn set_transient_storage(&mut self, address: Address, key: H256, value: H256) {
+		self.transient.set_transient_storage(address, key, value)
+	}
+
 	fn insert_cache(&self, address: &Address, account: AccountEntry) {
 		// Dirty account which is not in the cache means this is a new account.
 		// It goes directly into the checkpoint as there's nothing to rever to.
@@ -1174,15 +1224,11 @@ impl<B: Backend> State<B> {
 	/// Requires a secure trie to be used for accurate results.
 	/// `account_key` == keccak(address)
 	pub fn prove_account(&self, account_key: H256) -> TrieResult<(Vec<Bytes>, BasicAccount)> {
-		let mut recorder = Recorder::new();
 		let trie = TrieDB::new(self.db.as_hashdb(), &self.root)?;
-		let maybe_account: Option<BasicAccount> = {
-			let panicky_decoder = |bytes: &[u8]| {
-				::rlp::decode(bytes).expect(&format!("prove_account, could not query trie for account key={}", &account_key))
-			};
-			let query = (&mut recorder, panicky_decoder);
-			trie.get_with(&account_key, query)?
+		let panicky_decoder = |bytes: &[u8]| -> BasicAccount {
+			::rlp::decode(bytes).expect(&format!("prove_account, could not query trie for account key={}", &account_key))
 		};
+		let (proof, maybe_account) = proof::generate_proof_with(&trie, &account_key, panicky_decoder)?;
 		let account = maybe_account.unwrap_or_else(|| BasicAccount {
 			balance: 0.into(),
 			nonce: self.account_start_nonce,
@@ -1190,7 +1236,7 @@ impl<B: Backend> State<B> {
 			storage_root: KECCAK_NULL_RLP,
 		});
 
-		Ok((recorder.drain().into_iter().map(|r| r.data).collect(), account))
+		Ok((proof, account))
 	}
 
 	/// Prove an account's storage key's existence or nonexistence in the state.
@@ -1238,6 +1284,7 @@ impl Clone for State<StateDB> {
 			root: self.root.clone(),
 			cache: RefCell::new(cache),
 			checkpoints: RefCell::new(Vec::new()),
+			transient: TransientState::default(),
 			account_start_nonce: self.account_start_nonce.clone(),
 			factories: self.factories.clone(),
 		}
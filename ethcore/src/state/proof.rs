@@ -0,0 +1,111 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Merkle-Patricia proofs (EIP-1186).
+
+use ethereum_types::H256;
+use bytes::Bytes;
+use hashdb::HashDB;
+use keccak_hasher::KeccakHasher;
+use kvdb::DBValue;
+use memorydb::MemoryDB;
+use trie::{Trie, Recorder};
+use ethtrie::{TrieDB, Result as TrieResult};
+
+/// Prove the value of `key` in the trie, or its absence.
+/// Returns the encodings of all nodes on the path to the key, starting with the root.
+pub fn generate_proof(trie: &TrieDB, key: &[u8]) -> TrieResult<Vec<Bytes>> {
+	generate_proof_with(trie, key, |_: &[u8]| ()).map(|(proof, _)| proof)
+}
+
+/// Like `generate_proof`, but also returns the proven value decoded with `decoder`.
+pub fn generate_proof_with<T, F>(trie: &TrieDB, key: &[u8], decoder: F) -> TrieResult<(Vec<Bytes>, Option<T>)>
+	where F: FnOnce(&[u8]) -> T
+{
+	let mut recorder = Recorder::new();
+	let value = trie.get_with(key, (&mut recorder, decoder))?;
+	Ok((recorder.drain().into_iter().map(|r| r.data).collect(), value))
+}
+
+/// Check a proof generated by `generate_proof` against the trie root.
+/// Returns the proven value, `None` if the proof shows the key is absent,
+/// or an error if the proof doesn't lead from `root` to the key.
+pub fn verify_proof(root: &H256, key: &[u8], proof: &[Bytes]) -> TrieResult<Option<Bytes>> {
+	let mut db = MemoryDB::<KeccakHasher, DBValue>::new();
+	for node in proof {
+		db.insert(&node[..]);
+	}
+	let value = TrieDB::new(&db, root)?.get(key)?;
+	Ok(value.map(|v| v.into_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+	use ethereum_types::H256;
+	use keccak_hasher::KeccakHasher;
+	use kvdb::DBValue;
+	use memorydb::MemoryDB;
+	use trie::TrieMut;
+	use ethtrie::{TrieDB, TrieDBMut};
+	use super::{generate_proof, verify_proof};
+
+	#[test]
+	fn proofs_round_trip() {
+		let mut db = MemoryDB::<KeccakHasher, DBValue>::new();
+		let mut root = H256::new();
+		let items: Vec<(Vec<u8>, Vec<u8>)> = (0u8..64)
+			.map(|i| (vec![i / 16, i, 0xaa], vec![i; 40]))
+			.collect();
+		{
+			let mut trie = TrieDBMut::new(&mut db, &mut root);
+			for &(ref k, ref v) in &items {
+				trie.insert(k, v).unwrap();
+			}
+		}
+
+		let trie = TrieDB::new(&db, &root).unwrap();
+		for &(ref k, ref v) in &items {
+			let proof = generate_proof(&trie, k).unwrap();
+			assert_eq!(verify_proof(&root, k, &proof).unwrap(), Some(v.clone()));
+		}
+
+		// branch and leaf mismatch
+		for key in &[vec![0u8, 0, 0xab], vec![0x10u8]] {
+			let proof = generate_proof(&trie, key).unwrap();
+			assert!(!proof.is_empty());
+			assert_eq!(verify_proof(&root, key, &proof).unwrap(), None);
+		}
+	}
+
+	#[test]
+	fn rejects_bad_proofs() {
+		let mut db = MemoryDB::<KeccakHasher, DBValue>::new();
+		let mut root = H256::new();
+		{
+			let mut trie = TrieDBMut::new(&mut db, &mut root);
+			for i in 0u8..16 {
+				trie.insert(&[i, 1], &[i; 40]).unwrap();
+			}
+		}
+
+		let trie = TrieDB::new(&db, &root).unwrap();
+		let mut proof = generate_proof(&trie, &[3, 1]).unwrap();
+		assert!(verify_proof(&H256::from(1), &[3, 1], &proof).is_err());
+
+		proof.pop();
+		assert!(verify_proof(&root, &[3, 1], &proof).is_err());
+	}
+}
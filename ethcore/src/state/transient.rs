@@ -0,0 +1,201 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Transaction-scoped state.
+//!
+//! Holds the accessed addresses and storage keys (EIP-2929) and the transient
+//! storage (EIP-1153). Every change is recorded in a journal so that
+//! `revert_to_checkpoint` undoes only the changes made since the matching
+//! `checkpoint`, without copying the whole state per call frame.
+
+use std::collections::{HashMap, HashSet};
+use ethereum_types::{Address, H256};
+
+/// A change which can be undone when reverting to a checkpoint.
+#[derive(Debug)]
+enum JournalEntry {
+	/// The address was added to the accessed addresses.
+	AccessedAddress(Address),
+	/// The storage key was added to the accessed storage keys.
+	AccessedStorageKey(Address, H256),
+	/// The transient storage value was changed; holds the previous value.
+	TransientStorage(Address, H256, H256),
+}
+
+/// State which lives for the duration of a single transaction.
+#[derive(Debug, Default)]
+pub struct TransientState {
+	accessed_addresses: HashSet<Address>,
+	accessed_storage_keys: HashSet<(Address, H256)>,
+	transient_storage: HashMap<(Address, H256), H256>,
+	/// Changes made since the oldest active checkpoint.
+	journal: Vec<JournalEntry>,
+	/// Length of the journal at each active checkpoint.
+	checkpoints: Vec<usize>,
+}
+
+impl TransientState {
+	/// Forget everything; called at the start of each transaction.
+	pub fn clear(&mut self) {
+		*self = TransientState::default();
+	}
+
+	/// Create a recoverable checkpoint.
+	pub fn checkpoint(&mut self) {
+		self.checkpoints.push(self.journal.len());
+	}
+
+	/// Keep the changes made since the last checkpoint and discard it.
+	pub fn discard_checkpoint(&mut self) {
+		self.checkpoints.pop();
+		if self.checkpoints.is_empty() {
+			// nothing left which could revert these changes
+			self.journal.clear();
+		}
+	}
+
+	/// Undo the changes made since the last checkpoint and discard it.
+	pub fn revert_to_checkpoint(&mut self) {
+		if let Some(len) = self.checkpoints.pop() {
+			for entry in self.journal.drain(len..).rev() {
+				match entry {
+					JournalEntry::AccessedAddress(address) => {
+						self.accessed_addresses.remove(&address);
+					},
+					JournalEntry::AccessedStorageKey(address, key) => {
+						self.accessed_storage_keys.remove(&(address, key));
+					},
+					JournalEntry::TransientStorage(address, key, value) => {
+						self.transient_storage.insert((address, key), value);
+					},
+				}
+			}
+		}
+	}
+
+	/// Check if the address has been accessed in the current transaction.
+	pub fn is_address_accessed(&self, address: &Address) -> bool {
+		self.accessed_addresses.contains(address)
+	}
+
+	/// Mark the address as accessed in the current transaction.
+	pub fn access_address(&mut self, address: Address) {
+		if self.accessed_addresses.insert(address) && !self.checkpoints.is_empty() {
+			self.journal.push(JournalEntry::AccessedAddress(address));
+		}
+	}
+
+	/// Check if the storage key of `address` has been accessed in the current transaction.
+	pub fn is_storage_key_accessed(&self, address: &Address, key: &H256) -> bool {
+		self.accessed_storage_keys.contains(&(*address, *key))
+	}
+
+	/// Mark the storage key of `address` as accessed in the current transaction.
+	pub fn access_storage_key(&mut self, address: Address, key: H256) {
+		if self.accessed_storage_keys.insert((address, key)) && !self.checkpoints.is_empty() {
+			self.journal.push(JournalEntry::AccessedStorageKey(address, key));
+		}
+	}
+
+	/// Get the transient storage value of `address` for `key`.
+	pub fn transient_storage_at(&self, address: &Address, key: &H256) -> H256 {
+		self.transient_storage.get(&(*address, *key)).cloned().unwrap_or_else(H256::zero)
+	}
+
+	/// Set the transient storage value of `address` for `key`.
+	pub fn set_transient_storage(&mut self, address: Address, key: H256, value: H256) {
+		let previous = self.transient_storage.insert((address, key), value).unwrap_or_else(H256::zero);
+		if previous != value && !self.checkpoints.is_empty() {
+			self.journal.push(JournalEntry::TransientStorage(address, key, previous));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::TransientState;
+	use ethereum_types::{Address, H256};
+
+	#[test]
+	fn revert_undoes_only_the_last_checkpoint() {
+		let a = Address::from(1);
+		let b = Address::from(2);
+		let c = Address::from(3);
+		let key = H256::from(4);
+
+		let mut transient = TransientState::default();
+		transient.access_address(a);
+		transient.checkpoint();
+		transient.access_address(b);
+		transient.checkpoint();
+		transient.access_address(a);
+		transient.access_address(c);
+		transient.access_storage_key(b, key);
+		transient.revert_to_checkpoint();
+
+		assert!(transient.is_address_accessed(&a));
+		assert!(transient.is_address_accessed(&b));
+		assert!(!transient.is_address_accessed(&c));
+		assert!(!transient.is_storage_key_accessed(&b, &key));
+
+		transient.revert_to_checkpoint();
+		assert!(transient.is_address_accessed(&a));
+		assert!(!transient.is_address_accessed(&b));
+	}
+
+	#[test]
+	fn discarded_changes_are_reverted_by_parent_checkpoint() {
+		let a = Address::from(1);
+		let key = H256::from(2);
+
+		let mut transient = TransientState::default();
+		transient.checkpoint();
+		transient.checkpoint();
+		transient.access_address(a);
+		transient.access_storage_key(a, key);
+		transient.discard_checkpoint();
+		assert!(transient.is_address_accessed(&a));
+		assert!(transient.is_storage_key_accessed(&a, &key));
+
+		transient.revert_to_checkpoint();
+		assert!(!transient.is_address_accessed(&a));
+		assert!(!transient.is_storage_key_accessed(&a, &key));
+	}
+
+	#[test]
+	fn revert_restores_transient_storage() {
+		let a = Address::from(1);
+		let key = H256::from(2);
+
+		let mut transient = TransientState::default();
+		transient.set_transient_storage(a, key, H256::from(3));
+		transient.checkpoint();
+		transient.set_transient_storage(a, key, H256::from(4));
+		transient.checkpoint();
+		transient.set_transient_storage(a, key, H256::from(5));
+		transient.set_transient_storage(a, H256::from(6), H256::from(7));
+		transient.revert_to_checkpoint();
+
+		assert_eq!(transient.transient_storage_at(&a, &key), H256::from(4));
+		assert_eq!(transient.transient_storage_at(&a, &H256::from(6)), H256::zero());
+
+		transient.discard_checkpoint();
+		assert_eq!(transient.transient_storage_at(&a, &key), H256::from(4));
+
+		transient.clear();
+		assert_eq!(transient.transient_storage_at(&a, &key), H256::zero());
+	}
+}
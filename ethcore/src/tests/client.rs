@@ -38,6 +38,7 @@ use miner::MinerService;
 use tempdir::TempDir;
 use test_helpers;
 use verification::queue::kind::blocks::Unverified;
+use rustc_hex::FromHex;
 
 #[test]
 fn imports_from_empty() {
@@ -365,3 +366,152 @@ fn transaction_proof() {
 	assert_eq!(state.balance(&Address::default()).unwrap(), 5.into());
 	assert_eq!(state.balance(&address).unwrap(), 95.into());
 }
+
+#[test]
+fn account_and_storage_proof() {
+	use ::client::ProvingBlockChainClient;
+	use ethereum_types::H256;
+
+	let client = generate_dummy_client(0);
+	let address = Address::random();
+	let key = H256::from(1);
+	let value = H256::from(0xff);
+	let test_spec = Spec::new_test();
+
+	let mut b = client.prepare_open_block(Address::default(), (3141562.into(), 31415620.into()), vec![]).unwrap();
+	b.block_mut().state_mut().add_balance(&address, &5.into(), CleanupMode::NoEmpty).unwrap();
+	b.block_mut().state_mut().set_storage(&address, key, value).unwrap();
+	b.block_mut().state_mut().commit().unwrap();
+	let b = b.close_and_lock().unwrap().seal(&*test_spec.engine, vec![]).unwrap();
+	client.import_sealed_block(b).unwrap();
+
+	let (account_proof, account) = client.prove_account(keccak(&address), BlockId::Latest).unwrap();
+	let (storage_proof, proved_value) = client.prove_storage(keccak(&address), keccak(&key), BlockId::Latest).unwrap();
+	assert_eq!(account.balance, 5.into());
+	assert_eq!(proved_value, value);
+
+	// the proof nodes alone must be enough to read the values back from the state root.
+	let proof: Vec<_> = account_proof.iter().chain(&storage_proof).map(|node| ::kvdb::DBValue::from_slice(node)).collect();
+	let backend = state::backend::ProofCheck::new(&proof);
+
+	let mut factories = ::factory::Factories::default();
+	factories.accountdb = ::account_db::Factory::Plain; // raw state values, no mangled keys.
+	let root = *client.best_block_header().state_root();
+
+	let state = State::from_existing(backend, root, 0.into(), factories).unwrap();
+	assert_eq!(state.balance(&address).unwrap(), 5.into());
+	assert_eq!(state.storage_at(&address, &key).unwrap(), value);
+}
+
+#[test]
+fn estimate_gas_reports_revert() {
+	use client::{Call, StateClient};
+	use executed::CallError;
+
+	let client = test_helpers::generate_dummy_client_with_spec_and_data(ethereum::new_byzantium_test, 0, 0, &[]);
+	let address = Address::from(0x1000);
+	let mut state = client.latest_state();
+	// reverts with `Error("nope")`
+	state.new_contract(&address, 0.into(), 0.into()).unwrap();
+	state.init_code(&address, "7f08c379a000000000000000000000000000000000000000000000000000000000600052602060045260046024527f6e6f70650000000000000000000000000000000000000000000000000000000060445260646000fd".from_hex().unwrap()).unwrap();
+
+	let transaction = Transaction {
+		nonce: 0.into(),
+		gas_price: 0.into(),
+		gas: 21000.into(),
+		action: Action::Call(address),
+		value: 0.into(),
+		data: Vec::new(),
+	}.fake_sign(Address::default());
+
+	let output = "08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000046e6f706500000000000000000000000000000000000000000000000000000000".from_hex().unwrap();
+	assert_eq!(client.estimate_gas(&transaction, &state, &client.best_block_header()), Err(CallError::Reverted(output)));
+}
+
+#[test]
+fn estimate_gas_reports_exception() {
+	use client::{Call, StateClient};
+	use executed::CallError;
+
+	let client = test_helpers::generate_dummy_client_with_spec_and_data(ethereum::new_byzantium_test, 0, 0, &[]);
+	let address = Address::from(0x1000);
+	let mut state = client.latest_state();
+	// INVALID
+	state.new_contract(&address, 0.into(), 0.into()).unwrap();
+	state.init_code(&address, "fe".from_hex().unwrap()).unwrap();
+
+	let transaction = Transaction {
+		nonce: 0.into(),
+		gas_price: 0.into(),
+		gas: 21000.into(),
+		action: Action::Call(address),
+		value: 0.into(),
+		data: Vec::new(),
+	}.fake_sign(Address::default());
+
+	let error = ::vm::Error::BadInstruction { instruction: 0xfe };
+	assert_eq!(client.estimate_gas(&transaction, &state, &client.best_block_header()), Err(CallError::Exceptional(error)));
+}
+
+#[test]
+fn estimate_gas_reports_exceeded_gas_limit() {
+	use client::{Call, StateClient};
+	use executed::CallError;
+
+	let client = test_helpers::generate_dummy_client_with_spec_and_data(ethereum::new_byzantium_test, 0, 0, &[]);
+	let address = Address::from(0x1000);
+	let mut state = client.latest_state();
+	// JUMPDEST, PUSH1 0, JUMP: loops until out of gas
+	state.new_contract(&address, 0.into(), 0.into()).unwrap();
+	state.init_code(&address, "5b600056".from_hex().unwrap()).unwrap();
+
+	let transaction = Transaction {
+		nonce: 0.into(),
+		gas_price: 0.into(),
+		gas: 21000.into(),
+		action: Action::Call(address),
+		value: 0.into(),
+		data: Vec::new(),
+	}.fake_sign(Address::default());
+
+	let header = client.best_block_header();
+	let limit = *header.gas_limit() * U256::from(10);
+	assert_eq!(client.estimate_gas(&transaction, &state, &header), Err(CallError::ExceedsGasLimit(limit)));
+}
+
+#[test]
+fn call_with_tracer_reports_steps() {
+	use client::StateClient;
+	use trace::{RecordingTracer, EvmStep};
+
+	let client = test_helpers::generate_dummy_client_with_spec_and_data(ethereum::new_byzantium_test, 0, 0, &[]);
+	let address = Address::from(0x1000);
+	let mut state = client.latest_state();
+	// PUSH1 1, PUSH1 2, ADD, STOP
+	state.new_contract(&address, 0.into(), 0.into()).unwrap();
+	state.init_code(&address, "600160020100".from_hex().unwrap()).unwrap();
+
+	let transaction = Transaction {
+		nonce: 0.into(),
+		gas_price: 0.into(),
+		gas: 100_000.into(),
+		action: Action::Call(address),
+		value: 0.into(),
+		data: Vec::new(),
+	}.fake_sign(Address::default());
+
+	let executed = client.call_with_tracer(&transaction, RecordingTracer::default(), &mut state, &client.best_block_header()).unwrap();
+	let step = |pc: usize, instruction: u8, gas: u64, stack: &[u64]| EvmStep {
+		pc,
+		instruction,
+		gas: gas.into(),
+		stack: stack.iter().map(|&x| x.into()).collect(),
+		address,
+	};
+	assert_eq!(executed.vm_trace.unwrap().steps, vec![
+		step(0, 0x60, 79_000, &[]),
+		step(2, 0x60, 78_997, &[1]),
+		step(4, 0x01, 78_994, &[1, 2]),
+		step(5, 0x00, 78_991, &[3]),
+	]);
+}
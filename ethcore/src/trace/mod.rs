@@ -21,12 +21,14 @@ mod db;
 mod executive_tracer;
 mod import;
 mod noop_tracer;
+mod step_tracer;
 mod types;
 
 pub use self::config::Config;
 pub use self::db::TraceDB;
 pub use self::noop_tracer::{NoopTracer, NoopVMTracer};
 pub use self::executive_tracer::{ExecutiveTracer, ExecutiveVMTracer};
+pub use self::step_tracer::{StepVMTracer, RecordingTracer};
 pub use self::import::ImportRequest;
 pub use self::localized::LocalizedTrace;
 
@@ -82,6 +84,10 @@ pub trait VMTracer: Send {
 	/// @returns true if `trace_prepare_execute` and `trace_executed` should be called.
 	fn trace_next_instruction(&mut self, _pc: usize, _instruction: u8, _current_gas: U256) -> bool { false }
 
+	/// Trace a single step of the contract at `address`, with the stack as it is before the instruction.
+	/// Only called if `trace_next_instruction` returned `true`.
+	fn trace_step(&mut self, _address: &Address, _pc: usize, _instruction: u8, _current_gas: U256, _stack: &[U256]) {}
+
 	/// Trace the preparation to execute a single valid instruction.
 	fn trace_prepare_execute(&mut self, _pc: usize, _instruction: u8, _gas_cost: U256, _mem_written: Option<(usize, usize)>, _store_written: Option<(U256, U256)>) {}
 
@@ -98,6 +104,28 @@ pub trait VMTracer: Send {
 	fn drain(self) -> Option<Self::Output>;
 }
 
+/// A single EVM instruction about to be executed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EvmStep {
+	/// Program counter.
+	pub pc: usize,
+	/// Opcode of the instruction.
+	pub instruction: u8,
+	/// Gas remaining before the instruction.
+	pub gas: U256,
+	/// Stack before the instruction, bottom first.
+	pub stack: Vec<U256>,
+	/// Address of the contract being executed.
+	pub address: Address,
+}
+
+/// Receives a callback for every EVM instruction executed, including those of nested calls.
+/// Attach it to an execution by wrapping it in a `StepVMTracer`.
+pub trait StepTracer: Send {
+	/// Called before each instruction is executed.
+	fn on_step(&mut self, step: &EvmStep);
+}
+
 /// `DbExtras` provides an interface to query extra data which is not stored in tracesdb,
 /// but necessary to work correctly.
 pub trait DatabaseExtras {
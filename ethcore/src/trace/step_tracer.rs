@@ -0,0 +1,61 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Per-step tracing.
+
+use ethereum_types::{U256, Address};
+use trace::{VMTracer, StepTracer, EvmStep};
+
+/// VM tracer forwarding every executed instruction to a `StepTracer`.
+pub struct StepVMTracer<T>(T);
+
+impl<T: StepTracer> StepVMTracer<T> {
+	/// Create a new VM tracer driving given step tracer.
+	pub fn new(tracer: T) -> Self {
+		StepVMTracer(tracer)
+	}
+}
+
+impl<T: StepTracer> VMTracer for StepVMTracer<T> {
+	type Output = T;
+
+	fn trace_next_instruction(&mut self, _pc: usize, _instruction: u8, _current_gas: U256) -> bool { true }
+
+	fn trace_step(&mut self, address: &Address, pc: usize, instruction: u8, current_gas: U256, stack: &[U256]) {
+		self.0.on_step(&EvmStep {
+			pc,
+			instruction,
+			gas: current_gas,
+			stack: stack.to_vec(),
+			address: *address,
+		});
+	}
+
+	fn drain(self) -> Option<T> { Some(self.0) }
+}
+
+/// Step tracer recording every step.
+#[derive(Debug, Default)]
+pub struct RecordingTracer {
+	/// Steps in order of execution.
+	pub steps: Vec<EvmStep>,
+}
+
+impl StepTracer for RecordingTracer {
+	fn on_step(&mut self, step: &EvmStep) {
+		self.steps.push(step.clone());
+	}
+}
@@ -473,6 +473,7 @@ mod tests {
 					parent: *header.parent_hash(),
 					children: Vec::new(),
 					is_finalized: false,
+					empty_steps: 0,
 				}
 			})
 		}
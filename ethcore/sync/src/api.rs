@@ -640,6 +640,10 @@ pub struct NetworkConfiguration {
 	pub min_peers: u32,
 	/// Max pending peers.
 	pub max_pending_peers: u32,
+	/// Max incoming connections per subnet within `subnet_connection_window`.
+	pub max_connections_per_subnet: u32,
+	/// Window for `max_connections_per_subnet`.
+	pub subnet_connection_window: Duration,
 	/// Reserved snapshot sync peers.
 	pub snapshot_peers: u32,
 	/// List of reserved node addresses.
@@ -678,6 +682,8 @@ impl NetworkConfiguration {
 			max_peers: self.max_peers,
 			min_peers: self.min_peers,
 			max_handshakes: self.max_pending_peers,
+			max_connections_per_subnet: self.max_connections_per_subnet,
+			subnet_connection_window: self.subnet_connection_window,
 			reserved_protocols: hash_map![WARP_SYNC_PROTOCOL_ID => self.snapshot_peers],
 			reserved_nodes: self.reserved_nodes,
 			ip_filter: self.ip_filter,
@@ -702,6 +708,8 @@ impl From<BasicNetworkConfiguration> for NetworkConfiguration {
 			max_peers: other.max_peers,
 			min_peers: other.min_peers,
 			max_pending_peers: other.max_handshakes,
+			max_connections_per_subnet: other.max_connections_per_subnet,
+			subnet_connection_window: other.subnet_connection_window,
 			snapshot_peers: *other.reserved_protocols.get(&WARP_SYNC_PROTOCOL_ID).unwrap_or(&0),
 			reserved_nodes: other.reserved_nodes,
 			ip_filter: other.ip_filter,
@@ -31,6 +31,14 @@ pub enum Error {
 	/// Transaction has too low fee
 	/// (there is already a transaction with the same sender-nonce but higher gas price)
 	TooCheapToReplace,
+	/// Transaction's gas price is below the price required to replace
+	/// the transaction with the same sender and nonce.
+	InsufficientGasPriceBump {
+		/// Minimal gas price required for the replacement
+		minimal: U256,
+		/// Transaction gas price
+		got: U256,
+	},
 	/// Transaction was not imported to the queue because limit has been reached.
 	LimitReached,
 	/// Transaction's gas price is below threshold.
@@ -100,6 +108,8 @@ impl fmt::Display for Error {
 			AlreadyImported => "Already imported".into(),
 			Old => "No longer valid".into(),
 			TooCheapToReplace => "Gas price too low to replace".into(),
+			InsufficientGasPriceBump { minimal, got } =>
+				format!("Gas price too low to replace. Min={}, Given={}", minimal, got),
 			LimitReached => "Transaction limit reached".into(),
 			InsufficientGasPrice { minimal, got } =>
 				format!("Insufficient gas price. Min={}, Given={}", minimal, got),
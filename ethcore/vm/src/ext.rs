@@ -74,6 +74,12 @@ pub trait Ext {
 	/// Stores a value for given key.
 	fn set_storage(&mut self, key: H256, value: H256) -> Result<()>;
 
+	/// Returns a value for given key from the transient storage (EIP-1153).
+	fn transient_storage_at(&self, key: &H256) -> Result<H256>;
+
+	/// Stores a value for given key in the transient storage (EIP-1153).
+	fn set_transient_storage(&mut self, key: H256, value: H256) -> Result<()>;
+
 	/// Determine whether an account exists.
 	fn exists(&self, address: &Address) -> Result<bool>;
 
@@ -156,9 +162,24 @@ pub trait Ext {
 	/// Decrements sstore refunds counter.
 	fn sub_sstore_refund(&mut self, value: usize);
 
+	/// Check if the address has already been accessed in the current transaction (EIP-2929).
+	fn al_contains_address(&self, address: &Address) -> bool;
+
+	/// Mark the address as accessed in the current transaction (EIP-2929).
+	fn al_insert_address(&mut self, address: Address);
+
+	/// Check if the storage key of the current contract has already been accessed in the current transaction (EIP-2929).
+	fn al_contains_storage_key(&self, key: &H256) -> bool;
+
+	/// Mark the storage key of the current contract as accessed in the current transaction (EIP-2929).
+	fn al_insert_storage_key(&mut self, key: H256);
+
 	/// Decide if any more operations should be traced. Passthrough for the VM trace.
 	fn trace_next_instruction(&mut self, _pc: usize, _instruction: u8, _current_gas: U256) -> bool { false }
 
+	/// Trace a single step with the full stack as it is before the instruction. Passthrough for the VM trace.
+	fn trace_step(&mut self, _pc: usize, _instruction: u8, _current_gas: U256, _stack: &[U256]) {}
+
 	/// Prepare to trace an operation. Passthrough for the VM trace.
 	fn trace_prepare_execute(&mut self, _pc: usize, _instruction: u8, _gas_cost: U256, _mem_written: Option<(usize, usize)>, _store_written: Option<(U256, U256)>) {}
 
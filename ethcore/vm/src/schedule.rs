@@ -28,6 +28,12 @@ pub struct Schedule {
 	pub have_revert: bool,
 	/// Does it have a EXTCODEHASH instruction
 	pub have_extcodehash: bool,
+	/// Does it have TLOAD and TSTORE instructions
+	pub have_transient_storage: bool,
+	/// Does it have a PUSH0 instruction
+	pub have_push0: bool,
+	/// Does it have a MCOPY instruction
+	pub have_mcopy: bool,
 	/// VM stack limit
 	pub stack_limit: usize,
 	/// Max number of nested calls/creates
@@ -70,6 +76,8 @@ pub struct Schedule {
 	pub call_new_account_gas: usize,
 	/// Refund for SUICIDE
 	pub suicide_refund_gas: usize,
+	/// Refunds are capped at `gas_used / max_refund_quotient`
+	pub max_refund_quotient: usize,
 	/// Gas for used memory
 	pub memory_gas: usize,
 	/// Coefficient used to convert memory size to gas price for memory
@@ -119,6 +127,16 @@ pub struct Schedule {
 	pub kill_dust: CleanDustMode,
 	/// Enable EIP-1283 rules
 	pub eip1283: bool,
+	/// Enable EIP-2929 rules (cold/warm state access)
+	pub eip2929: bool,
+	/// Gas price for the first `SLOAD` of a storage slot in a transaction (EIP-2929)
+	pub cold_sload_cost: usize,
+	/// Gas price for the first access of an account in a transaction (EIP-2929)
+	pub cold_account_access_cost: usize,
+	/// Gas price for accessing an already accessed account or storage slot (EIP-2929)
+	pub warm_storage_read_cost: usize,
+	/// Reject new contracts starting with the 0xEF byte (EIP-3541)
+	pub eip3541: bool,
 	/// VM execution does not increase null signed address nonce if this field is true.
 	pub keep_unsigned_nonce: bool,
 	/// Wasm extra schedule settings, if wasm activated
@@ -210,6 +228,9 @@ impl Schedule {
 			have_return_data: false,
 			have_bitwise_shifting: false,
 			have_extcodehash: false,
+			have_transient_storage: false,
+			have_push0: false,
+			have_mcopy: false,
 			stack_limit: 1024,
 			max_depth: 1024,
 			tier_step_gas: [0, 2, 3, 5, 8, 10, 20, 0],
@@ -231,6 +252,7 @@ impl Schedule {
 			call_value_transfer_gas: 9000,
 			call_new_account_gas: 25000,
 			suicide_refund_gas: 24000,
+			max_refund_quotient: 2,
 			memory_gas: 3,
 			quad_coeff_div: 512,
 			create_data_gas: 200,
@@ -253,6 +275,11 @@ impl Schedule {
 			have_static_call: false,
 			kill_dust: CleanDustMode::Off,
 			eip1283: false,
+			eip2929: false,
+			cold_sload_cost: 2100,
+			cold_account_access_cost: 2600,
+			warm_storage_read_cost: 100,
+			eip3541: false,
 			keep_unsigned_nonce: false,
 			wasm: None,
 		}
@@ -284,6 +311,9 @@ impl Schedule {
 			have_return_data: false,
 			have_bitwise_shifting: false,
 			have_extcodehash: false,
+			have_transient_storage: false,
+			have_push0: false,
+			have_mcopy: false,
 			stack_limit: 1024,
 			max_depth: 1024,
 			tier_step_gas: [0, 2, 3, 5, 8, 10, 20, 0],
@@ -305,6 +335,7 @@ impl Schedule {
 			call_value_transfer_gas: 9000,
 			call_new_account_gas: 25000,
 			suicide_refund_gas: 24000,
+			max_refund_quotient: 2,
 			memory_gas: 3,
 			quad_coeff_div: 512,
 			create_data_gas: 200,
@@ -327,6 +358,11 @@ impl Schedule {
 			have_static_call: false,
 			kill_dust: CleanDustMode::Off,
 			eip1283: false,
+			eip2929: false,
+			cold_sload_cost: 2100,
+			cold_account_access_cost: 2600,
+			warm_storage_read_cost: 100,
+			eip3541: false,
 			keep_unsigned_nonce: false,
 			wasm: None,
 		}
@@ -55,6 +55,7 @@ pub struct FakeCall {
 #[derive(Default)]
 pub struct FakeExt {
 	pub store: HashMap<H256, H256>,
+	pub transient_store: HashMap<H256, H256>,
 	pub suicides: HashSet<Address>,
 	pub calls: HashSet<FakeCall>,
 	pub sstore_clears: i128,
@@ -67,6 +68,8 @@ pub struct FakeExt {
 	pub balances: HashMap<Address, U256>,
 	pub tracing: bool,
 	pub is_static: bool,
+	pub accessed_addresses: HashSet<Address>,
+	pub accessed_storage_keys: HashSet<H256>,
 }
 
 // similar to the normal `finalize` function, but ignoring NeedsReturn.
@@ -98,6 +101,15 @@ impl FakeExt {
 		ext
 	}
 
+	/// New fake externalities with constantinople schedule rules and EIP-2929 enabled
+	pub fn new_eip2929() -> Self {
+		let mut ext = FakeExt::new_constantinople();
+		ext.schedule.eip2929 = true;
+		ext.schedule.sload_gas = ext.schedule.warm_storage_read_cost;
+		ext.schedule.sstore_reset_gas -= ext.schedule.cold_sload_cost;
+		ext
+	}
+
 	/// Alter fake externalities to allow wasm
 	pub fn with_wasm(mut self) -> Self {
 		self.schedule.wasm = Some(Default::default());
@@ -119,6 +131,15 @@ impl Ext for FakeExt {
 		Ok(())
 	}
 
+	fn transient_storage_at(&self, key: &H256) -> Result<H256> {
+		Ok(self.transient_store.get(key).unwrap_or(&H256::new()).clone())
+	}
+
+	fn set_transient_storage(&mut self, key: H256, value: H256) -> Result<()> {
+		self.transient_store.insert(key, value);
+		Ok(())
+	}
+
 	fn exists(&self, address: &Address) -> Result<bool> {
 		Ok(self.balances.contains_key(address))
 	}
@@ -239,6 +260,22 @@ impl Ext for FakeExt {
 		self.sstore_clears -= value as i128;
 	}
 
+	fn al_contains_address(&self, address: &Address) -> bool {
+		self.accessed_addresses.contains(address)
+	}
+
+	fn al_insert_address(&mut self, address: Address) {
+		self.accessed_addresses.insert(address);
+	}
+
+	fn al_contains_storage_key(&self, key: &H256) -> bool {
+		self.accessed_storage_keys.contains(key)
+	}
+
+	fn al_insert_storage_key(&mut self, key: H256) {
+		self.accessed_storage_keys.insert(key);
+	}
+
 	fn trace_next_instruction(&mut self, _pc: usize, _instruction: u8, _gas: U256) -> bool {
 		self.tracing
 	}
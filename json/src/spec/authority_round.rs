@@ -67,6 +67,12 @@ pub struct AuthorityRoundParams {
 	/// Maximum number of accepted empty steps.
 	#[serde(rename="maximumEmptySteps")]
 	pub maximum_empty_steps: Option<Uint>,
+	/// Block at which the number of empty steps in a block starts being limited.
+	#[serde(rename="emptyStepsLimitTransition")]
+	pub empty_steps_limit_transition: Option<Uint>,
+	/// Maximum number of empty steps in a block. Defaults to twice the number of validators.
+	#[serde(rename="maximumEmptyStepsPerBlock")]
+	pub maximum_empty_steps_per_block: Option<Uint>,
 }
 
 /// Authority engine deserialization.
@@ -97,7 +103,9 @@ mod tests {
 				"validateStepTransition": 150,
 				"blockReward": 5000000,
 				"maximumUncleCountTransition": 10000000,
-				"maximumUncleCount": 5
+				"maximumUncleCount": 5,
+				"emptyStepsLimitTransition": 20,
+				"maximumEmptyStepsPerBlock": 8
 			}
 		}"#;
 
@@ -108,6 +116,8 @@ mod tests {
 		assert_eq!(deserialized.params.immediate_transitions, None);
 		assert_eq!(deserialized.params.maximum_uncle_count_transition, Some(Uint(10_000_000.into())));
 		assert_eq!(deserialized.params.maximum_uncle_count, Some(Uint(5.into())));
+		assert_eq!(deserialized.params.empty_steps_limit_transition, Some(Uint(20.into())));
+		assert_eq!(deserialized.params.maximum_empty_steps_per_block, Some(Uint(8.into())));
 
 	}
 }
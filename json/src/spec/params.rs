@@ -112,6 +112,24 @@ pub struct Params {
 	/// See `CommonParams` docs.
 	#[serde(rename="eip1283Transition")]
 	pub eip1283_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip1153Transition")]
+	pub eip1153_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip3855Transition")]
+	pub eip3855_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip5656Transition")]
+	pub eip5656_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip2929Transition")]
+	pub eip2929_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip3529Transition")]
+	pub eip3529_transition: Option<Uint>,
+	/// See `CommonParams` docs.
+	#[serde(rename="eip3541Transition")]
+	pub eip3541_transition: Option<Uint>,
 	#[serde(rename="eip1014Transition")]
 	pub eip1014_transition: Option<Uint>,
 	/// See `CommonParams` docs.
@@ -211,11 +211,20 @@ impl TransactionQueue {
 		limits: txpool::Options,
 		verification_options: verifier::Options,
 		strategy: PrioritizationStrategy,
+	) -> Self {
+		Self::with_scoring(limits, verification_options, scoring::NonceAndGasPrice::new(strategy))
+	}
+
+	/// Create new queue with given pool limits, initial verification options and transaction scoring.
+	pub fn with_scoring(
+		limits: txpool::Options,
+		verification_options: verifier::Options,
+		scoring: scoring::NonceAndGasPrice,
 	) -> Self {
 		let max_count = limits.max_count;
 		TransactionQueue {
 			insertion_id: Default::default(),
-			pool: RwLock::new(txpool::Pool::new(Default::default(), scoring::NonceAndGasPrice(strategy), limits)),
+			pool: RwLock::new(txpool::Pool::new(Default::default(), scoring, limits)),
 			options: RwLock::new(verification_options),
 			cached_pending: RwLock::new(CachedPending::none()),
 			recently_rejected: RecentlyRejected::new(cmp::max(MIN_REJECTED_CACHE_SIZE, max_count / 4)),
@@ -286,7 +295,9 @@ impl TransactionQueue {
 				let imported = verifier
 					.verify_transaction(transaction)
 					.and_then(|verified| {
-						self.pool.write().import(verified).map_err(convert_error)
+						let gas_price = verified.signed().gas_price;
+						let mut pool = self.pool.write();
+						pool.import(verified).map_err(|err| convert_error(&pool, gas_price, err))
 					});
 
 				match imported {
@@ -573,13 +584,19 @@ impl TransactionQueue {
 	}
 }
 
-fn convert_error(err: txpool::Error) -> transaction::Error {
+fn convert_error(pool: &Pool, gas_price: U256, err: txpool::Error) -> transaction::Error {
 	use self::txpool::ErrorKind;
 
 	match *err.kind() {
 		ErrorKind::AlreadyImported(..) => transaction::Error::AlreadyImported,
 		ErrorKind::TooCheapToEnter(..) => transaction::Error::LimitReached,
-		ErrorKind::TooCheapToReplace(..) => transaction::Error::TooCheapToReplace,
+		ErrorKind::TooCheapToReplace(ref old_hash, _) => match pool.find(old_hash) {
+			Some(old) => transaction::Error::InsufficientGasPriceBump {
+				minimal: pool.scoring().min_replacement_gas_price(&old.signed().gas_price),
+				got: gas_price,
+			},
+			None => transaction::Error::TooCheapToReplace,
+		},
 		ref e => {
 			warn!(target: "txqueue", "Unknown import error: {:?}", e);
 			transaction::Error::NotAllowed
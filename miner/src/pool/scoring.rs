@@ -33,14 +33,23 @@ use ethereum_types::U256;
 use txpool::{self, scoring};
 use super::{verifier, PrioritizationStrategy, VerifiedTransaction, ScoredTransaction};
 
+/// Default gas price bump (in percent).
+///
 /// Transaction with the same (sender, nonce) can be replaced only if
-/// `new_gas_price > old_gas_price + old_gas_price >> SHIFT`
-const GAS_PRICE_BUMP_SHIFT: usize = 3; // 2 = 25%, 3 = 12.5%, 4 = 6.25%
+/// `new_gas_price >= old_gas_price * (100 + bump_percent) / 100`
+pub const DEFAULT_GAS_PRICE_BUMP_PERCENT: usize = 10;
 
 /// Calculate minimal gas price requirement.
 #[inline]
-fn bump_gas_price(old_gp: U256) -> U256 {
-	old_gp.saturating_add(old_gp >> GAS_PRICE_BUMP_SHIFT)
+fn bump_gas_price(old_gp: U256, bump_percent: usize) -> U256 {
+	let (bump, overflow) = old_gp.overflowing_mul(bump_percent.into());
+	if overflow {
+		return U256::max_value();
+	}
+
+	// round up, so that the new gas price is never below the required percentage
+	let bump = bump / 100 + if (bump % 100).is_zero() { 0 } else { 1 };
+	old_gp.saturating_add(bump)
 }
 
 /// Simple, gas-price based scoring for transactions.
@@ -48,9 +57,27 @@ fn bump_gas_price(old_gp: U256) -> U256 {
 /// NOTE: Currently penalization does not apply to new transactions that enter the pool.
 /// We might want to store penalization status in some persistent state.
 #[derive(Debug, Clone)]
-pub struct NonceAndGasPrice(pub PrioritizationStrategy);
+pub struct NonceAndGasPrice {
+	/// Strategy used to prioritize transactions.
+	pub strategy: PrioritizationStrategy,
+	/// Minimal gas price increase (in percent) required to replace a transaction with the same (sender, nonce).
+	pub bump_percent: usize,
+}
 
 impl NonceAndGasPrice {
+	/// Create new scoring with given prioritization strategy and the default gas price bump.
+	pub fn new(strategy: PrioritizationStrategy) -> Self {
+		NonceAndGasPrice {
+			strategy,
+			bump_percent: DEFAULT_GAS_PRICE_BUMP_PERCENT,
+		}
+	}
+
+	/// Minimal gas price required to replace a transaction with given gas price.
+	pub fn min_replacement_gas_price(&self, old_gas_price: &U256) -> U256 {
+		bump_gas_price(*old_gas_price, self.bump_percent)
+	}
+
 	/// Decide if the transaction should even be considered into the pool (if the pool is full).
 	///
 	/// Used by Verifier to quickly reject transactions that don't have any chance to get into the pool later on,
@@ -83,7 +110,7 @@ impl<P> txpool::Scoring<P> for NonceAndGasPrice where P: ScoredTransaction + txp
 		let old_gp = old.gas_price();
 		let new_gp = new.gas_price();
 
-		let min_required_gp = bump_gas_price(*old_gp);
+		let min_required_gp = self.min_replacement_gas_price(old_gp);
 
 		match min_required_gp.cmp(&new_gp) {
 			cmp::Ordering::Greater => scoring::Choice::RejectNew,
@@ -161,7 +188,7 @@ mod tests {
 
 	#[test]
 	fn should_replace_same_sender_by_nonce() {
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 
 		let tx1 = Tx {
 			nonce: 1,
@@ -175,7 +202,7 @@ mod tests {
 		};
 		let tx3 = Tx {
 			nonce: 2,
-			gas_price: 110,
+			gas_price: 109,
 			..Default::default()
 		};
 		let tx4 = Tx {
@@ -199,10 +226,44 @@ mod tests {
 		assert_eq!(scoring.should_replace(&txs[3], &txs[1]), RejectNew);
 	}
 
+	#[test]
+	fn should_require_configured_gas_price_bump() {
+		let scoring = NonceAndGasPrice {
+			strategy: PrioritizationStrategy::GasPriceOnly,
+			bump_percent: 25,
+		};
+
+		let keypair = Random.generate().unwrap();
+		let txs = vec![100, 124, 125, 126].into_iter().map(|gas_price| {
+			Tx {
+				nonce: 1,
+				gas_price,
+				..Default::default()
+			}.unsigned().sign(keypair.secret(), None).verified()
+		}).collect::<Vec<_>>();
+
+		// just under
+		assert_eq!(scoring.choose(&txs[0], &txs[1]), RejectNew);
+		// exact boundary
+		assert_eq!(scoring.choose(&txs[0], &txs[2]), ReplaceOld);
+		// just over
+		assert_eq!(scoring.choose(&txs[0], &txs[3]), ReplaceOld);
+	}
+
+	#[test]
+	fn should_round_gas_price_bump_up() {
+		assert_eq!(bump_gas_price(0.into(), 10), 0.into());
+		assert_eq!(bump_gas_price(1.into(), 10), 2.into());
+		assert_eq!(bump_gas_price(100.into(), 10), 110.into());
+		assert_eq!(bump_gas_price(101.into(), 10), 112.into());
+		assert_eq!(bump_gas_price(100.into(), 0), 100.into());
+		assert_eq!(bump_gas_price(U256::max_value(), 10), U256::max_value());
+	}
+
 	#[test]
 	fn should_replace_different_sender_by_priority_and_gas_price() {
 		// given
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let tx_regular_low_gas = {
 			let tx = Tx {
 				nonce: 1,
@@ -253,7 +314,7 @@ mod tests {
 	#[test]
 	fn should_calculate_score_correctly() {
 		// given
-		let scoring = NonceAndGasPrice(PrioritizationStrategy::GasPriceOnly);
+		let scoring = NonceAndGasPrice::new(PrioritizationStrategy::GasPriceOnly);
 		let (tx1, tx2, tx3) = Tx::default().signed_triple();
 		let transactions = vec![tx1, tx2, tx3].into_iter().enumerate().map(|(i, tx)| {
 			let mut verified = tx.verified();
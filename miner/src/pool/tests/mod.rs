@@ -15,10 +15,11 @@
 // along with Parity.  If not, see <http://www.gnu.org/licenses/>.
 
 use ethereum_types::U256;
+use ethkey::{Random, Generator};
 use transaction::{self, PendingTransaction};
 use txpool;
 
-use pool::{verifier, TransactionQueue, PrioritizationStrategy, PendingSettings, PendingOrdering};
+use pool::{scoring, verifier, TransactionQueue, PrioritizationStrategy, PendingSettings, PendingOrdering};
 
 pub mod tx;
 pub mod client;
@@ -579,12 +580,55 @@ fn should_not_replace_same_transaction_if_the_fee_is_less_than_minimal_bump() {
 	let res = txq.import(client.clone(), vec![tx2, tx4].local());
 
 	// then
-	assert_eq!(res, vec![Err(transaction::Error::TooCheapToReplace), Ok(())]);
+	assert_eq!(res, vec![Err(transaction::Error::InsufficientGasPriceBump {
+		minimal: 22.into(),
+		got: 21.into(),
+	}), Ok(())]);
 	assert_eq!(txq.status().status.transaction_count, 2);
 	assert_eq!(txq.pending(client.clone(), PendingSettings::all_prioritized(0, 0))[0].signed().gas_price, U256::from(20));
 	assert_eq!(txq.pending(client.clone(), PendingSettings::all_prioritized(0, 0))[1].signed().gas_price, U256::from(2));
 }
 
+#[test]
+fn should_require_configured_gas_price_bump_to_replace() {
+	// given
+	let txq = TransactionQueue::with_scoring(
+		txpool::Options {
+			max_count: 3,
+			max_per_sender: 3,
+			max_mem_usage: 50
+		},
+		verifier::Options {
+			minimal_gas_price: 1.into(),
+			block_gas_limit: 1_000_000.into(),
+			tx_gas_limit: 1_000_000.into(),
+			no_early_reject: false,
+		},
+		scoring::NonceAndGasPrice {
+			strategy: PrioritizationStrategy::GasPriceOnly,
+			bump_percent: 50,
+		},
+	);
+	let keypair = Random.generate().unwrap();
+	let tx = |gas_price| Tx::gas_price(gas_price).unsigned().sign(keypair.secret(), None);
+	let client = TestClient::new().with_balance(10_000_000);
+
+	// when
+	let res = txq.import(client.clone(), vec![tx(100)].local());
+	assert_eq!(res, vec![Ok(())]);
+
+	// then
+	let res = txq.import(client.clone(), vec![tx(149)].local());
+	assert_eq!(res, vec![Err(transaction::Error::InsufficientGasPriceBump {
+		minimal: 150.into(),
+		got: 149.into(),
+	})]);
+	let res = txq.import(client.clone(), vec![tx(150)].local());
+	assert_eq!(res, vec![Ok(())]);
+	assert_eq!(txq.status().status.transaction_count, 1);
+	assert_eq!(txq.pending(client.clone(), PendingSettings::all_prioritized(0, 0))[0].signed().gas_price, U256::from(150));
+}
+
 #[test]
 fn should_return_none_when_transaction_from_given_address_does_not_exist() {
 	// given
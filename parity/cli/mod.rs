@@ -435,6 +435,10 @@ usage! {
 			"--max-pending-peers=[NUM]",
 			"Allow up to NUM pending connections.",
 
+			ARG arg_net_max_connections_per_subnet: (u16) = 4u16, or |c: &Config| c.network.as_ref()?.max_connections_per_subnet.clone(),
+			"--net-max-connections-per-subnet=[NUM]",
+			"Accept up to NUM incoming connections from the same /24 (IPv4) or /48 (IPv6) subnet every 10 seconds. 0 disables the limit.",
+
 			ARG arg_network_id: (Option<u64>) = None, or |c: &Config| c.network.as_ref()?.id.clone(),
 			"--network-id=[INDEX]",
 			"Override the network identifier from the chain we are on.",
@@ -737,6 +741,10 @@ usage! {
 			"--tx-queue-strategy=[S]",
 			"Prioritization strategy used to order transactions in the queue. S may be: gas_price - Prioritize txs with high gas price",
 
+			ARG arg_tx_queue_bump_percent: (usize) = 10usize, or |c: &Config| c.mining.as_ref()?.tx_queue_bump_percent.clone(),
+			"--tx-queue-bump-percent=[PERCENT]",
+			"Minimal gas price increase, in percent, required to replace a transaction with the same sender and nonce in the queue.",
+
 			ARG arg_stratum_interface: (String) = "local", or |c: &Config| c.stratum.as_ref()?.interface.clone(),
 			"--stratum-interface=[IP]",
 			"Interface address for Stratum server.",
@@ -1192,6 +1200,7 @@ struct Network {
 	max_peers: Option<u16>,
 	snapshot_peers: Option<u16>,
 	max_pending_peers: Option<u16>,
+	max_connections_per_subnet: Option<u16>,
 	nat: Option<String>,
 	allow_ips: Option<String>,
 	id: Option<u64>,
@@ -1318,6 +1327,7 @@ struct Mining {
 	tx_queue_per_sender: Option<usize>,
 	tx_queue_mem_limit: Option<u32>,
 	tx_queue_strategy: Option<String>,
+	tx_queue_bump_percent: Option<usize>,
 	tx_queue_ban_count: Option<u16>,
 	tx_queue_ban_time: Option<u16>,
 	tx_queue_no_unfamiliar_locals: Option<bool>,
@@ -1656,6 +1666,7 @@ mod tests {
 			arg_min_peers: Some(25u16),
 			arg_max_peers: Some(50u16),
 			arg_max_pending_peers: 64u16,
+			arg_net_max_connections_per_subnet: 4u16,
 			arg_snapshot_peers: 0u16,
 			arg_allow_ips: "all".into(),
 			arg_nat: "any".into(),
@@ -1752,6 +1763,7 @@ mod tests {
 			arg_tx_queue_per_sender: None,
 			arg_tx_queue_mem_limit: 4u32,
 			arg_tx_queue_strategy: "gas_factor".into(),
+			arg_tx_queue_bump_percent: 10usize,
 			arg_tx_queue_ban_count: Some(1u16),
 			arg_tx_queue_ban_time: Some(180u16),
 			flag_remove_solved: false,
@@ -1922,6 +1934,7 @@ mod tests {
 				min_peers: Some(10),
 				max_peers: Some(20),
 				max_pending_peers: Some(30),
+				max_connections_per_subnet: None,
 				snapshot_peers: Some(40),
 				allow_ips: Some("public".into()),
 				nat: Some("any".into()),
@@ -2018,6 +2031,7 @@ mod tests {
 				tx_queue_per_sender: None,
 				tx_queue_mem_limit: None,
 				tx_queue_strategy: None,
+				tx_queue_bump_percent: None,
 				tx_queue_ban_count: None,
 				tx_queue_ban_time: None,
 				tx_queue_no_unfamiliar_locals: None,
@@ -488,6 +488,10 @@ impl Configuration {
 		self.args.arg_max_pending_peers as u32
 	}
 
+	fn max_connections_per_subnet(&self) -> u32 {
+		self.args.arg_net_max_connections_per_subnet as u32
+	}
+
 	fn snapshot_peers(&self) -> u32 {
 		self.args.arg_snapshot_peers as u32
 	}
@@ -544,6 +548,7 @@ impl Configuration {
 
 			tx_queue_penalization: to_queue_penalization(self.args.arg_tx_time_limit)?,
 			tx_queue_strategy: to_queue_strategy(&self.args.arg_tx_queue_strategy)?,
+			tx_queue_bump_percent: self.args.arg_tx_queue_bump_percent,
 			tx_queue_no_unfamiliar_locals: self.args.flag_tx_queue_no_unfamiliar_locals,
 			refuse_service_transactions: self.args.flag_refuse_service_transactions,
 
@@ -717,6 +722,7 @@ impl Configuration {
 		ret.snapshot_peers = self.snapshot_peers();
 		ret.ip_filter = self.ip_filter()?;
 		ret.max_pending_peers = self.max_pending_peers();
+		ret.max_connections_per_subnet = self.max_connections_per_subnet();
 		let mut net_path = PathBuf::from(self.directories().base);
 		net_path.push("network");
 		ret.config_path = Some(net_path.to_str().unwrap().to_owned());
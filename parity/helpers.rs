@@ -196,6 +196,8 @@ pub fn default_network_config() -> ::sync::NetworkConfiguration {
 		min_peers: 25,
 		snapshot_peers: 0,
 		max_pending_peers: 64,
+		max_connections_per_subnet: 4,
+		subnet_connection_window: Duration::from_secs(10),
 		ip_filter: IpFilter::default(),
 		reserved_nodes: Vec::new(),
 		allow_non_reserved: true,
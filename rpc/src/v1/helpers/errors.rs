@@ -158,11 +158,19 @@ pub fn state_corrupt() -> Error {
 	internal("State corrupt", "")
 }
 
-pub fn exceptional() -> Error {
+pub fn exceeds_gas_limit<T: fmt::Display>(limit: T) -> Error {
+	Error {
+		code: ErrorCode::ServerError(codes::EXECUTION_ERROR),
+		message: "The execution requires more gas than the upper limit.".into(),
+		data: Some(Value::String(format!("Requires higher than upper limit of {}", limit))),
+	}
+}
+
+pub fn exceptional<T: fmt::Display>(data: T) -> Error {
 	Error {
 		code: ErrorCode::ServerError(codes::EXCEPTION_ERROR),
 		message: "The execution failed due to an exception.".into(),
-		data: None,
+		data: Some(Value::String(data.to_string())),
 	}
 }
 
@@ -319,6 +327,9 @@ pub fn transaction_message(error: &TransactionError) -> String {
 		TooCheapToReplace => {
 			"Transaction gas price is too low. There is another transaction with same nonce in the queue. Try increasing the gas price or incrementing the nonce.".into()
 		},
+		InsufficientGasPriceBump { minimal, got } => {
+			format!("Transaction gas price is too low to replace another transaction with same nonce in the queue (minimal: {}, got: {}). Try increasing the gas price or incrementing the nonce.", minimal, got)
+		},
 		LimitReached => {
 			"There are too many transactions in the queue. Your transaction was dropped due to limit. Try increasing the fee.".into()
 		},
@@ -388,7 +399,9 @@ pub fn call(error: CallError) -> Error {
 	match error {
 		CallError::StatePruned => state_pruned(),
 		CallError::StateCorrupt => state_corrupt(),
-		CallError::Exceptional => exceptional(),
+		CallError::Exceptional(e) => exceptional(e),
+		CallError::Reverted(output) => vm(&VMError::Reverted, &output),
+		CallError::ExceedsGasLimit(limit) => exceeds_gas_limit(limit),
 		CallError::Execution(e) => execution(e),
 		CallError::TransactionNotFound => internal("{}, this should not be the case with eth_call, most likely a bug.", CallError::TransactionNotFound),
 	}
@@ -398,7 +411,10 @@ pub fn vm(error: &VMError, output: &[u8]) -> Error {
 	use rustc_hex::ToHex;
 
 	let data = match error {
-		&VMError::Reverted => format!("{} 0x{}", VMError::Reverted, output.to_hex()),
+		&VMError::Reverted => match revert_reason(output) {
+			Some(reason) => format!("{} 0x{} ({})", VMError::Reverted, output.to_hex(), reason),
+			None => format!("{} 0x{}", VMError::Reverted, output.to_hex()),
+		},
 		error => format!("{}", error),
 	};
 
@@ -409,6 +425,28 @@ pub fn vm(error: &VMError, output: &[u8]) -> Error {
 	}
 }
 
+/// Decodes the message of a revert with `Error(string)`.
+fn revert_reason(output: &[u8]) -> Option<String> {
+	const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+	if output.len() < 4 + 64 || output[..4] != ERROR_SELECTOR {
+		return None;
+	}
+	let data = &output[4..];
+	let word = |pos: usize| -> Option<usize> {
+		let word = data.get(pos..pos.checked_add(32)?)?;
+		if word[..24].iter().any(|b| *b != 0) {
+			return None;
+		}
+		Some(word[24..].iter().fold(0usize, |acc, b| (acc << 8) | *b as usize))
+	};
+	let offset = word(0)?;
+	let len = word(offset)?;
+	let start = offset.checked_add(32)?;
+	let bytes = data.get(start..start.checked_add(len)?)?;
+	String::from_utf8(bytes.to_vec()).ok()
+}
+
 pub fn unknown_block() -> Error {
 	Error {
 		code: ErrorCode::InvalidParams,
@@ -492,3 +530,17 @@ pub fn on_demand_others(err: &OnDemandError) -> Error {
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use rustc_hex::FromHex;
+	use super::revert_reason;
+
+	#[test]
+	fn should_decode_revert_reason() {
+		let output: Vec<u8> = "08c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000046e6f706500000000000000000000000000000000000000000000000000000000".from_hex().unwrap();
+
+		assert_eq!(revert_reason(&output), Some("nope".into()));
+		assert_eq!(revert_reason(&output[..40]), None);
+		assert_eq!(revert_reason(&[0u8; 100]), None);
+	}
+}
@@ -41,7 +41,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, LightBlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus,
-	AccountInfo, HwAccountInfo, Header, RichHeader, Receipt,
+	AccountInfo, HwAccountInfo, Header, RichHeader, Receipt, BlockInfo,
 };
 use Host;
 
@@ -397,6 +397,10 @@ impl Parity for ParityClient {
 		Box::new(self.fetcher().header(id).and_then(from_encoded))
 	}
 
+	fn block_info(&self, _number: Trailing<BlockNumber>) -> Result<BlockInfo> {
+		Err(errors::light_unimplemented(None))
+	}
+
 	fn block_receipts(&self, number: Trailing<BlockNumber>) -> BoxFuture<Vec<Receipt>> {
 		let id = number.unwrap_or_default().to_block_id();
 		Box::new(self.fetcher().receipts(id).and_then(|receipts| Ok(receipts.into_iter().map(Into::into).collect())))
@@ -45,7 +45,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus,
-	AccountInfo, HwAccountInfo, RichHeader, Receipt,
+	AccountInfo, HwAccountInfo, RichHeader, Receipt, BlockInfo,
 	block_number_to_id
 };
 use Host;
@@ -407,6 +407,27 @@ impl<C, M, U, S> Parity for ParityClient<C, M, U> where
 		}))
 	}
 
+	fn block_info(&self, number: Trailing<BlockNumber>) -> Result<BlockInfo> {
+		let id = match number.unwrap_or_default() {
+			BlockNumber::Num(num) => BlockId::Number(num),
+			BlockNumber::Earliest => BlockId::Earliest,
+			BlockNumber::Latest => BlockId::Latest,
+			// the pending block isn't in the chain yet
+			BlockNumber::Pending => return Err(errors::unknown_block()),
+		};
+
+		let hash = self.client.block_hash(id.clone()).ok_or_else(errors::unknown_block)?;
+		let details = self.client.block_details(id).ok_or_else(errors::unknown_block)?;
+
+		Ok(BlockInfo {
+			number: details.number.into(),
+			hash: hash.into(),
+			total_difficulty: details.total_difficulty.into(),
+			is_finalized: details.is_finalized,
+			empty_steps: details.empty_steps.into(),
+		})
+	}
+
 	fn block_receipts(&self, number: Trailing<BlockNumber>) -> BoxFuture<Vec<Receipt>> {
 		let number = number.unwrap_or_default();
 
@@ -16,7 +16,8 @@
 
 use std::sync::Arc;
 use ethcore::account_provider::AccountProvider;
-use ethcore::client::{TestBlockChainClient, Executed, TransactionId};
+use ethcore::client::{BlockChainClient, TestBlockChainClient, EachBlockWith, Executed, TransactionId};
+use ethcore::ids::BlockId;
 use ethcore::receipt::{LocalizedReceipt, TransactionOutcome};
 use ethcore_logger::RotatingLogger;
 use ethereum_types::{Address, U256, H256};
@@ -522,6 +523,32 @@ fn rpc_parity_call() {
 	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
 }
 
+#[test]
+fn rpc_parity_block_info() {
+	let deps = Dependencies::new();
+	deps.client.add_blocks(2, EachBlockWith::Nothing);
+	let hash = deps.client.block_hash(BlockId::Number(1)).unwrap();
+	let io = deps.default_client();
+
+	let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_getBlockInfo",
+		"params": ["0x1"],
+		"id": 1
+	}"#;
+	let response = format!(r#"{{"jsonrpc":"2.0","result":{{"number":"0x1","hash":"0x{:?}","totalDifficulty":"0x0","isFinalized":false,"emptySteps":"0x0"}},"id":1}}"#, hash);
+	assert_eq!(io.handle_request_sync(request), Some(response));
+
+	let request = r#"{
+		"jsonrpc": "2.0",
+		"method": "parity_getBlockInfo",
+		"params": ["pending"],
+		"id": 1
+	}"#;
+	let response = r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"Unknown block number"},"id":1}"#;
+	assert_eq!(io.handle_request_sync(request), Some(response.to_owned()));
+}
+
 #[test]
 fn rpc_parity_block_receipts() {
 	let deps = Dependencies::new();
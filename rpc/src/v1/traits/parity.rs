@@ -27,7 +27,7 @@ use v1::types::{
 	TransactionStats, LocalTransactionStatus,
 	BlockNumber, ConsensusCapability, VersionInfo,
 	OperationsInfo, ChainStatus,
-	AccountInfo, HwAccountInfo, RichHeader, Receipt,
+	AccountInfo, HwAccountInfo, RichHeader, Receipt, BlockInfo,
 };
 
 build_rpc_trait! {
@@ -205,6 +205,11 @@ build_rpc_trait! {
 		#[rpc(name = "parity_getBlockHeaderByNumber")]
 		fn block_header(&self, Trailing<BlockNumber>) -> BoxFuture<RichHeader>;
 
+		/// Get chain metadata of a block: total difficulty, finality and the number of sealed empty steps.
+		/// If no parameter is provided defaults to `latest`.
+		#[rpc(name = "parity_getBlockInfo")]
+		fn block_info(&self, Trailing<BlockNumber>) -> Result<BlockInfo>;
+
 		/// Get block receipts.
 		/// Allows you to fetch receipts from the entire block at once.
 		/// If no parameter is provided defaults to `latest`.
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity.  If not, see <http://www.gnu.org/licenses/>.
 
+use v1::types::{Bytes, H160, H256, U256};
+
 /// Account information.
 #[derive(Debug, Default, Clone, PartialEq, Serialize)]
 pub struct AccountInfo {
@@ -41,3 +43,34 @@ pub struct HwAccountInfo {
 	/// Device manufacturer.
 	pub manufacturer: String,
 }
+
+/// Account state together with its Merkle proof (used by `eth_getProof`, EIP-1186).
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthAccount {
+	/// Account address
+	pub address: H160,
+	/// Account balance
+	pub balance: U256,
+	/// Account nonce
+	pub nonce: U256,
+	/// Hash of the account code
+	pub code_hash: H256,
+	/// Root of the account storage trie
+	pub storage_hash: H256,
+	/// Trie nodes (in order from the state root) proving the account
+	pub account_proof: Vec<Bytes>,
+	/// Proofs for the requested storage keys
+	pub storage_proof: Vec<StorageProof>,
+}
+
+/// Storage value together with its Merkle proof (used by `eth_getProof`, EIP-1186).
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct StorageProof {
+	/// Storage key
+	pub key: U256,
+	/// Storage value
+	pub value: U256,
+	/// Trie nodes (in order from the storage root) proving the value
+	pub proof: Vec<Bytes>,
+}
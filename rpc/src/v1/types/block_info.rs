@@ -0,0 +1,57 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Block info.
+
+use v1::types::{H256, U256};
+
+/// Chain metadata of a block.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct BlockInfo {
+	/// Block number
+	pub number: U256,
+	/// Block hash
+	pub hash: H256,
+	/// Total difficulty of the block and all its parents
+	#[serde(rename="totalDifficulty")]
+	pub total_difficulty: U256,
+	/// Whether the block is considered finalized
+	#[serde(rename="isFinalized")]
+	pub is_finalized: bool,
+	/// Number of empty step messages sealed in the block
+	#[serde(rename="emptySteps")]
+	pub empty_steps: U256,
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_json;
+	use super::BlockInfo;
+
+	#[test]
+	fn test_serialize_block_info() {
+		let info = BlockInfo {
+			number: 5.into(),
+			hash: 6u64.into(),
+			total_difficulty: 7.into(),
+			is_finalized: true,
+			empty_steps: 2.into(),
+		};
+
+		let serialized = serde_json::to_string(&info).unwrap();
+		assert_eq!(serialized, r#"{"number":"0x5","hash":"0x0000000000000000000000000000000000000000000000000000000000000006","totalDifficulty":"0x7","isFinalized":true,"emptySteps":"0x2"}"#);
+	}
+}
@@ -0,0 +1,469 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ethereum Node Records (EIP-778) using the "v4" identity scheme.
+//!
+//! A record is an RLP list `[signature, seq, k, v, ...]` with keys sorted and unique,
+//! signed by the node's secp256k1 key. Its textual form is `enr:` followed by the
+//! URL-safe base64 of the RLP, without padding.
+
+extern crate base64;
+extern crate ethereum_types;
+extern crate ethkey;
+extern crate keccak_hash as hash;
+extern crate rlp;
+
+#[cfg(test)]
+extern crate rustc_hex;
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::str::FromStr;
+use std::{error, fmt};
+
+use ethereum_types::{H256, H512};
+use ethkey::{KeyPair, Public, Secret, Signature};
+use hash::keccak;
+use rlp::{Decodable, Encodable, DecoderError, Rlp, RlpStream};
+
+/// Maximum size of an encoded record in bytes.
+pub const MAX_RECORD_SIZE: usize = 300;
+
+const ID_V4: &'static [u8] = b"v4";
+
+/// Node record errors.
+#[derive(Debug)]
+pub enum Error {
+	/// Textual form doesn't start with `enr:` or isn't valid base64.
+	InvalidText,
+	/// Encoded record exceeds `MAX_RECORD_SIZE`.
+	TooLarge,
+	/// Malformed RLP.
+	Rlp(DecoderError),
+	/// Keys are not sorted or not unique.
+	UnsortedKeys,
+	/// Identity scheme is missing or is not "v4".
+	UnknownScheme,
+	/// Signature doesn't match the public key of the record.
+	InvalidSignature,
+	/// Malformed enode URL.
+	InvalidEnode,
+	/// Given secret doesn't belong to the node.
+	KeyMismatch,
+	/// Key error.
+	Key(ethkey::Error),
+}
+
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Error::InvalidText => write!(f, "Invalid textual node record"),
+			Error::TooLarge => write!(f, "Node record is larger than {} bytes", MAX_RECORD_SIZE),
+			Error::Rlp(ref e) => write!(f, "Invalid node record RLP: {}", e),
+			Error::UnsortedKeys => write!(f, "Node record keys are not sorted or not unique"),
+			Error::UnknownScheme => write!(f, "Unknown node record identity scheme"),
+			Error::InvalidSignature => write!(f, "Invalid node record signature"),
+			Error::InvalidEnode => write!(f, "Invalid enode URL"),
+			Error::KeyMismatch => write!(f, "Secret doesn't belong to the node"),
+			Error::Key(ref e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl error::Error for Error {
+	fn description(&self) -> &str {
+		"Node record error"
+	}
+}
+
+impl From<DecoderError> for Error {
+	fn from(err: DecoderError) -> Self {
+		Error::Rlp(err)
+	}
+}
+
+impl From<ethkey::Error> for Error {
+	fn from(err: ethkey::Error) -> Self {
+		Error::Key(err)
+	}
+}
+
+/// Ethereum Node Record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeRecord {
+	seq: u64,
+	/// RLP-encoded values by key.
+	pairs: BTreeMap<Vec<u8>, Vec<u8>>,
+	signature: Vec<u8>,
+}
+
+impl NodeRecord {
+	/// Create an empty, unsigned record.
+	pub fn new(seq: u64) -> Self {
+		NodeRecord {
+			seq,
+			pairs: BTreeMap::new(),
+			signature: Vec::new(),
+		}
+	}
+
+	/// Create a signed record from an enode URL (`enode://<id>@<ip>:<port>[?discport=<port>]`).
+	/// `secret` has to be the key of the node, since a record is only valid when signed by it.
+	pub fn from_enode(url: &str, secret: &Secret) -> Result<Self, Error> {
+		let (id, address, udp) = parse_enode(url)?;
+		if KeyPair::from_secret(secret.clone())?.public() != &id {
+			return Err(Error::KeyMismatch);
+		}
+
+		let mut record = NodeRecord::new(1);
+		record.set_ip(address.ip());
+		match address {
+			SocketAddr::V4(_) => {
+				record.set_tcp(address.port());
+				record.set_udp(udp.unwrap_or(address.port()));
+			},
+			SocketAddr::V6(_) => {
+				record.set_tcp6(address.port());
+				record.set_udp6(udp.unwrap_or(address.port()));
+			},
+		}
+		record.sign(secret)?;
+		Ok(record)
+	}
+
+	/// Decode and verify a record from its RLP.
+	pub fn from_rlp(bytes: &[u8]) -> Result<Self, Error> {
+		if bytes.len() > MAX_RECORD_SIZE {
+			return Err(Error::TooLarge);
+		}
+
+		let rlp = Rlp::new(bytes);
+		let count = rlp.item_count()?;
+		if count < 2 || count % 2 != 0 {
+			return Err(DecoderError::RlpIncorrectListLen.into());
+		}
+
+		let mut record = NodeRecord::new(rlp.val_at(1)?);
+		record.signature = rlp.val_at(0)?;
+		for i in 1..count / 2 {
+			let key: Vec<u8> = rlp.val_at(2 * i)?;
+			if record.pairs.keys().next_back().map_or(false, |last| *last >= key) {
+				return Err(Error::UnsortedKeys);
+			}
+			record.pairs.insert(key, rlp.at(2 * i + 1)?.as_raw().to_vec());
+		}
+
+		if record.get::<Vec<u8>>(b"id").map_or(true, |id| id != ID_V4) {
+			return Err(Error::UnknownScheme);
+		}
+		if !record.verify() {
+			return Err(Error::InvalidSignature);
+		}
+		Ok(record)
+	}
+
+	/// RLP of the record.
+	pub fn to_rlp(&self) -> Vec<u8> {
+		let mut s = RlpStream::new_list(2 + 2 * self.pairs.len());
+		s.append(&self.signature);
+		self.append_content(&mut s);
+		s.out()
+	}
+
+	/// Sequence number.
+	pub fn seq(&self) -> u64 {
+		self.seq
+	}
+
+	/// Set sequence number. Invalidates the signature.
+	pub fn set_seq(&mut self, seq: u64) {
+		self.seq = seq;
+		self.signature.clear();
+	}
+
+	/// Signature, empty for unsigned records.
+	pub fn signature(&self) -> &[u8] {
+		&self.signature
+	}
+
+	/// RLP-encoded value of given key.
+	pub fn get_raw(&self, key: &[u8]) -> Option<&[u8]> {
+		self.pairs.get(key).map(|v| &v[..])
+	}
+
+	/// Decoded value of given key.
+	pub fn get<T: Decodable>(&self, key: &[u8]) -> Option<T> {
+		self.pairs.get(key).and_then(|v| Rlp::new(v).as_val().ok())
+	}
+
+	/// Set the value of given key. Invalidates the signature.
+	pub fn set<T: Encodable>(&mut self, key: &[u8], value: &T) {
+		let mut s = RlpStream::new();
+		s.append(value);
+		self.pairs.insert(key.to_vec(), s.out());
+		self.signature.clear();
+	}
+
+	/// Compressed secp256k1 public key.
+	pub fn public_key(&self) -> Option<Vec<u8>> {
+		self.get(b"secp256k1")
+	}
+
+	/// IPv4 address.
+	pub fn ip(&self) -> Option<Ipv4Addr> {
+		self.get::<Vec<u8>>(b"ip").and_then(|ip| match ip.len() {
+			4 => Some(Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3])),
+			_ => None,
+		})
+	}
+
+	/// IPv6 address.
+	pub fn ip6(&self) -> Option<Ipv6Addr> {
+		self.get::<Vec<u8>>(b"ip6").and_then(|ip| match ip.len() {
+			16 => {
+				let mut octets = [0u8; 16];
+				octets.copy_from_slice(&ip);
+				Some(Ipv6Addr::from(octets))
+			},
+			_ => None,
+		})
+	}
+
+	/// Set the IP address, as `ip` or `ip6` depending on its kind.
+	pub fn set_ip(&mut self, ip: IpAddr) {
+		match ip {
+			IpAddr::V4(ip) => self.set(b"ip", &ip.octets().to_vec()),
+			IpAddr::V6(ip) => self.set(b"ip6", &ip.octets().to_vec()),
+		}
+	}
+
+	/// TCP port.
+	pub fn tcp(&self) -> Option<u16> {
+		self.get(b"tcp")
+	}
+
+	/// Set TCP port.
+	pub fn set_tcp(&mut self, port: u16) {
+		self.set(b"tcp", &port)
+	}
+
+	/// UDP port.
+	pub fn udp(&self) -> Option<u16> {
+		self.get(b"udp")
+	}
+
+	/// Set UDP port.
+	pub fn set_udp(&mut self, port: u16) {
+		self.set(b"udp", &port)
+	}
+
+	/// IPv6-specific TCP port.
+	pub fn tcp6(&self) -> Option<u16> {
+		self.get(b"tcp6")
+	}
+
+	/// Set IPv6-specific TCP port.
+	pub fn set_tcp6(&mut self, port: u16) {
+		self.set(b"tcp6", &port)
+	}
+
+	/// IPv6-specific UDP port.
+	pub fn udp6(&self) -> Option<u16> {
+		self.get(b"udp6")
+	}
+
+	/// Set IPv6-specific UDP port.
+	pub fn set_udp6(&mut self, port: u16) {
+		self.set(b"udp6", &port)
+	}
+
+	/// Sign the record with the "v4" scheme, setting `id` and `secp256k1`.
+	pub fn sign(&mut self, secret: &Secret) -> Result<(), Error> {
+		let public = *KeyPair::from_secret(secret.clone())?.public();
+		self.set(b"id", &ID_V4.to_vec());
+		self.set(b"secp256k1", &compress(&public).to_vec());
+
+		let signature = ethkey::sign(secret, &self.content_hash())?;
+		self.signature = signature[..64].to_vec();
+		Ok(())
+	}
+
+	/// Check that the record is signed by its `secp256k1` key.
+	pub fn verify(&self) -> bool {
+		let public_key = match self.public_key() {
+			Some(ref key) if key.len() == 33 => key.clone(),
+			_ => return false,
+		};
+		if self.signature.len() != 64 {
+			return false;
+		}
+
+		let hash = self.content_hash();
+		let r = H256::from_slice(&self.signature[..32]);
+		let s = H256::from_slice(&self.signature[32..]);
+		(0..2).any(|v| {
+			ethkey::recover(&Signature::from_rsv(&r, &s, v), &hash)
+				.map_or(false, |public| compress(&public)[..] == public_key[..])
+		})
+	}
+
+	fn append_content(&self, s: &mut RlpStream) {
+		s.append(&self.seq);
+		for (key, value) in &self.pairs {
+			s.append(key);
+			s.append_raw(value, 1);
+		}
+	}
+
+	fn content_hash(&self) -> H256 {
+		let mut s = RlpStream::new_list(1 + 2 * self.pairs.len());
+		self.append_content(&mut s);
+		keccak(s.out())
+	}
+}
+
+impl fmt::Display for NodeRecord {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "enr:{}", base64::encode_config(&self.to_rlp(), base64::URL_SAFE_NO_PAD))
+	}
+}
+
+impl FromStr for NodeRecord {
+	type Err = Error;
+
+	fn from_str(s: &str) -> Result<Self, Error> {
+		if !s.starts_with("enr:") {
+			return Err(Error::InvalidText);
+		}
+		let bytes = base64::decode_config(&s[4..], base64::URL_SAFE_NO_PAD).map_err(|_| Error::InvalidText)?;
+		NodeRecord::from_rlp(&bytes)
+	}
+}
+
+fn compress(public: &Public) -> [u8; 33] {
+	let mut key = [0u8; 33];
+	key[0] = 2 + (public[63] & 1);
+	key[1..].copy_from_slice(&public[..32]);
+	key
+}
+
+fn parse_enode(url: &str) -> Result<(Public, SocketAddr, Option<u16>), Error> {
+	if !url.starts_with("enode://") {
+		return Err(Error::InvalidEnode);
+	}
+
+	let mut parts = url[8..].splitn(2, '@');
+	let id = parts.next().unwrap_or("");
+	let rest = parts.next().ok_or(Error::InvalidEnode)?;
+	if id.len() != 128 {
+		return Err(Error::InvalidEnode);
+	}
+	let id = H512::from_str(id).map_err(|_| Error::InvalidEnode)?;
+
+	let mut parts = rest.splitn(2, '?');
+	let address = parts.next().unwrap_or("").parse().map_err(|_| Error::InvalidEnode)?;
+	let udp = match parts.next() {
+		Some(query) if query.starts_with("discport=") => Some(query[9..].parse().map_err(|_| Error::InvalidEnode)?),
+		Some(_) => return Err(Error::InvalidEnode),
+		None => None,
+	};
+
+	Ok((id, address, udp))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::{IpAddr, Ipv4Addr};
+	use std::str::FromStr;
+	use ethkey::{KeyPair, Secret};
+	use rustc_hex::FromHex;
+	use super::{NodeRecord, Error};
+
+	// Reference record from EIP-778.
+	const RECORD: &'static str = "enr:-IS4QHCYrYZbAKWCBRlAy5zzaDZXJBGkcnh4MHcBFZntXNFrdvJjX04jRzjzCBOonrkTfj499SZuOh8R33Ls8RRcy5wBgmlkgnY0gmlwhH8AAAGJc2VjcDI1NmsxoQPKY0yuDUmstAHYpMa2_oxVtw0RW_QAdpzBQA8yWM0xOIN1ZHCCdl8";
+	const RECORD_RLP: &'static str = "f884b8407098ad865b00a582051940cb9cf36836572411a47278783077011599ed5cd16b76f2635f4e234738f30813a89eb9137e3e3df5266e3a1f11df72ecf1145ccb9c01826964827634826970847f00000189736563703235366b31a103ca634cae0d49acb401d8a4c6b6fe8c55b70d115bf400769cc1400f3258cd31388375647082765f";
+	const SECRET: &'static str = "b71c71a67e1177ad4e901695e1b4b9ee17ae16c6668d313eac2f96dbcda3f291";
+
+	#[test]
+	fn decodes_reference_record() {
+		let record = NodeRecord::from_str(RECORD).unwrap();
+		assert_eq!(record.seq(), 1);
+		assert_eq!(record.ip(), Some(Ipv4Addr::new(127, 0, 0, 1)));
+		assert_eq!(record.udp(), Some(30303));
+		assert_eq!(record.tcp(), None);
+		assert_eq!(record.public_key(), Some("03ca634cae0d49acb401d8a4c6b6fe8c55b70d115bf400769cc1400f3258cd3138".from_hex().unwrap()));
+		assert!(record.verify());
+		assert_eq!(record.to_rlp(), RECORD_RLP.from_hex().unwrap());
+		assert_eq!(record.to_string(), RECORD);
+	}
+
+	#[test]
+	fn signs_reference_record() {
+		let mut record = NodeRecord::new(1);
+		record.set_ip(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+		record.set_udp(30303);
+		assert!(!record.verify());
+
+		record.sign(&Secret::from_str(SECRET).unwrap()).unwrap();
+		assert!(record.verify());
+		assert_eq!(record.to_string(), RECORD);
+	}
+
+	#[test]
+	fn rejects_invalid_records() {
+		// udp changed to 30304
+		let mut rlp = RECORD_RLP.from_hex().unwrap();
+		*rlp.last_mut().unwrap() = 0x60;
+		match NodeRecord::from_rlp(&rlp) {
+			Err(Error::InvalidSignature) => {},
+			other => panic!("unexpected {:?}", other),
+		}
+
+		match NodeRecord::from_str(&RECORD[1..]) {
+			Err(Error::InvalidText) => {},
+			other => panic!("unexpected {:?}", other),
+		}
+
+		match NodeRecord::from_rlp(&[0u8; 301]) {
+			Err(Error::TooLarge) => {},
+			other => panic!("unexpected {:?}", other),
+		}
+	}
+
+	#[test]
+	fn converts_enode() {
+		let secret = Secret::from_str(SECRET).unwrap();
+		let public = *KeyPair::from_secret(secret.clone()).unwrap().public();
+
+		let record = NodeRecord::from_enode(&format!("enode://{:x}@127.0.0.1:30303?discport=30301", public), &secret).unwrap();
+		assert!(record.verify());
+		assert_eq!(record.ip(), Some(Ipv4Addr::new(127, 0, 0, 1)));
+		assert_eq!(record.tcp(), Some(30303));
+		assert_eq!(record.udp(), Some(30301));
+		assert_eq!(NodeRecord::from_str(&record.to_string()).unwrap(), record);
+
+		let record = NodeRecord::from_enode(&format!("enode://{:x}@[::1]:30303", public), &secret).unwrap();
+		assert_eq!(record.ip6(), Some("::1".parse().unwrap()));
+		assert_eq!(record.tcp6(), Some(30303));
+		assert_eq!(record.udp6(), Some(30303));
+
+		let other = Secret::from_str("a100df7a048e50ed308ea696dc600215098141cb391e9527329df289f9383f65").unwrap();
+		match NodeRecord::from_enode(&format!("enode://{:x}@127.0.0.1:30303", public), &other) {
+			Err(Error::KeyMismatch) => {},
+			other => panic!("unexpected {:?}", other),
+		}
+	}
+}
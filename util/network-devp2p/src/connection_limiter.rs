@@ -0,0 +1,144 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rate limiting of incoming connections per source subnet.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::{Duration, Instant};
+
+struct Bucket {
+	tokens: f64,
+	updated: Instant,
+}
+
+/// Token bucket per `/24` IPv4 or `/48` IPv6 subnet. Each subnet may open up to `limit`
+/// connections in a burst, refilled at `limit` connections per `window`.
+pub struct ConnectionLimiter {
+	limit: u32,
+	window: Duration,
+	buckets: HashMap<IpAddr, Bucket>,
+}
+
+impl ConnectionLimiter {
+	/// Create a new limiter. A `limit` of 0 disables it.
+	pub fn new(limit: u32, window: Duration) -> Self {
+		ConnectionLimiter {
+			limit,
+			window,
+			buckets: HashMap::new(),
+		}
+	}
+
+	/// Record a connection attempt from `ip` and return whether it should be accepted.
+	pub fn allow(&mut self, ip: IpAddr, now: Instant) -> bool {
+		if self.limit == 0 {
+			return true;
+		}
+
+		let limit = self.limit as f64;
+		let rate = limit / as_secs(self.window);
+		let bucket = self.buckets.entry(subnet(ip)).or_insert(Bucket { tokens: limit, updated: now });
+		bucket.tokens = (bucket.tokens + as_secs(now.duration_since(bucket.updated)) * rate).min(limit);
+		bucket.updated = now;
+		if bucket.tokens >= 1.0 {
+			bucket.tokens -= 1.0;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Forget subnets whose buckets have refilled completely.
+	pub fn prune(&mut self, now: Instant) {
+		let window = self.window;
+		self.buckets.retain(|_, bucket| now.duration_since(bucket.updated) < window);
+	}
+}
+
+fn as_secs(duration: Duration) -> f64 {
+	duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1e-9
+}
+
+fn subnet(ip: IpAddr) -> IpAddr {
+	match ip {
+		IpAddr::V4(ip) => {
+			let o = ip.octets();
+			IpAddr::V4(Ipv4Addr::new(o[0], o[1], o[2], 0))
+		},
+		IpAddr::V6(ip) => {
+			let s = ip.segments();
+			IpAddr::V6(Ipv6Addr::new(s[0], s[1], s[2], 0, 0, 0, 0, 0))
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::net::IpAddr;
+	use std::time::{Duration, Instant};
+	use super::ConnectionLimiter;
+
+	fn ip(s: &str) -> IpAddr {
+		s.parse().unwrap()
+	}
+
+	#[test]
+	fn limits_flood_from_subnet() {
+		let mut limiter = ConnectionLimiter::new(4, Duration::from_secs(10));
+		let now = Instant::now();
+
+		let accepted = (0..100).filter(|i| limiter.allow(ip(&format!("10.0.0.{}", i)), now)).count();
+		assert_eq!(accepted, 4);
+		assert!(limiter.allow(ip("10.0.1.1"), now));
+		assert!(limiter.allow(ip("192.168.0.1"), now));
+
+		// one token every 2.5 seconds
+		assert!(!limiter.allow(ip("10.0.0.1"), now + Duration::from_secs(2)));
+		assert!(limiter.allow(ip("10.0.0.1"), now + Duration::from_secs(3)));
+		assert!(!limiter.allow(ip("10.0.0.1"), now + Duration::from_secs(3)));
+	}
+
+	#[test]
+	fn groups_ipv6_by_48_prefix() {
+		let mut limiter = ConnectionLimiter::new(1, Duration::from_secs(10));
+		let now = Instant::now();
+
+		assert!(limiter.allow(ip("2001:db8:1:1::1"), now));
+		assert!(!limiter.allow(ip("2001:db8:1:2::1"), now));
+		assert!(limiter.allow(ip("2001:db8:2::1"), now));
+	}
+
+	#[test]
+	fn zero_limit_disables() {
+		let mut limiter = ConnectionLimiter::new(0, Duration::from_secs(10));
+		let now = Instant::now();
+		assert!((0..100).all(|_| limiter.allow(ip("10.0.0.1"), now)));
+	}
+
+	#[test]
+	fn prunes_refilled_buckets() {
+		let mut limiter = ConnectionLimiter::new(1, Duration::from_secs(10));
+		let now = Instant::now();
+		assert!(limiter.allow(ip("10.0.0.1"), now));
+
+		limiter.prune(now + Duration::from_secs(5));
+		assert!(!limiter.allow(ip("10.0.0.1"), now + Duration::from_secs(5)));
+
+		limiter.prune(now + Duration::from_secs(15));
+		assert!(limiter.buckets.is_empty());
+	}
+}
@@ -24,7 +24,7 @@ use std::cmp::{min, max};
 use std::path::{Path, PathBuf};
 use std::io::{Read, Write, self};
 use std::fs;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use ethkey::{KeyPair, Secret, Random, Generator};
 use hash::keccak;
 use mio::*;
@@ -42,7 +42,8 @@ use network::{NetworkConfiguration, NetworkIoMessage, ProtocolId, PeerId, Packet
 use network::{NonReservedPeerMode, NetworkContext as NetworkContextTrait};
 use network::{SessionInfo, Error, ErrorKind, DisconnectReason, NetworkProtocolHandler};
 use discovery::{Discovery, TableUpdates, NodeEntry, MAX_DATAGRAM_SIZE};
-use ip_utils::{map_external_address, select_public_address};
+use ip_utils::{map_external_address, select_public_address, PortMapping};
+use connection_limiter::ConnectionLimiter;
 use parity_path::restrict_permissions_owner;
 use parking_lot::{Mutex, RwLock};
 use network::{ConnectionFilter, ConnectionDirection};
@@ -62,6 +63,7 @@ const DISCOVERY_REFRESH: TimerToken = SYS_TIMER + 4;
 const FAST_DISCOVERY_REFRESH: TimerToken = SYS_TIMER + 5;
 const DISCOVERY_ROUND: TimerToken = SYS_TIMER + 6;
 const NODE_TABLE: TimerToken = SYS_TIMER + 7;
+const NAT_REFRESH: TimerToken = SYS_TIMER + 8;
 const FIRST_SESSION: StreamToken = 0;
 const LAST_SESSION: StreamToken = FIRST_SESSION + MAX_SESSIONS - 1;
 const USER_TIMER: TimerToken = LAST_SESSION + 256;
@@ -78,6 +80,8 @@ const FAST_DISCOVERY_REFRESH_TIMEOUT: Duration = Duration::from_secs(10);
 const DISCOVERY_ROUND_TIMEOUT: Duration = Duration::from_millis(300);
 // for NODE_TABLE TimerToken
 const NODE_TABLE_TIMEOUT: Duration = Duration::from_secs(300);
+// for NAT_REFRESH TimerToken, well within the requested lease
+const NAT_REFRESH_TIMEOUT: Duration = Duration::from_secs(20 * 60);
 
 #[derive(Debug, PartialEq, Eq)]
 /// Protocol info
@@ -261,6 +265,8 @@ pub struct Host {
 	reserved_nodes: RwLock<HashSet<NodeId>>,
 	stopping: AtomicBool,
 	filter: Option<Arc<ConnectionFilter>>,
+	connection_limiter: Mutex<ConnectionLimiter>,
+	port_mapping: Mutex<Option<PortMapping>>,
 }
 
 impl Host {
@@ -295,6 +301,7 @@ impl Host {
 		let boot_nodes = config.boot_nodes.clone();
 		let reserved_nodes = config.reserved_nodes.clone();
 		config.max_handshakes = min(config.max_handshakes, MAX_HANDSHAKES as u32);
+		let connection_limiter = ConnectionLimiter::new(config.max_connections_per_subnet, config.subnet_connection_window);
 
 		let mut host = Host {
 			info: RwLock::new(HostInfo {
@@ -317,6 +324,8 @@ impl Host {
 			reserved_nodes: RwLock::new(HashSet::new()),
 			stopping: AtomicBool::new(false),
 			filter,
+			connection_limiter: Mutex::new(connection_limiter),
+			port_mapping: Mutex::new(None),
 		};
 
 		for n in boot_nodes {
@@ -418,6 +427,10 @@ impl Host {
 			trace!(target: "network", "Disconnecting on shutdown: {}", p);
 			self.kill_connection(p, io, true);
 		}
+		if let Some(mapping) = self.port_mapping.lock().take() {
+			trace!(target: "network", "Releasing NAT port mapping on shutdown");
+			mapping.release();
+		}
 		io.unregister_handler();
 	}
 
@@ -448,8 +461,11 @@ impl Host {
 				let public_endpoint = NodeEndpoint { address: public_address, udp_port: local_endpoint.udp_port };
 				if self.info.read().config.nat_enabled {
 					match map_external_address(&local_endpoint) {
-						Some(endpoint) => {
-							info!("NAT mapped to external address {}", endpoint.address);
+						Some(mapping) => {
+							info!("NAT mapped to external address {}", mapping.external.address);
+							let endpoint = mapping.external.clone();
+							*self.port_mapping.lock() = Some(mapping);
+							io.register_timer(NAT_REFRESH, NAT_REFRESH_TIMEOUT)?;
 							endpoint
 						},
 						None => public_endpoint
@@ -496,6 +512,7 @@ impl Host {
 	fn maintain_network(&self, io: &IoContext<NetworkIoMessage>) {
 		self.keep_alive(io);
 		self.connect_peers(io);
+		self.connection_limiter.lock().prune(Instant::now());
 	}
 
 	fn have_session(&self, id: &NodeId) -> bool {
@@ -664,7 +681,15 @@ impl Host {
 		trace!(target: "network", "Accepting incoming connection");
 		loop {
 			let socket = match self.tcp_listener.lock().accept() {
-				Ok((sock, _addr)) => sock,
+				Ok((sock, addr)) => {
+					if !self.connection_limiter.lock().allow(addr.ip(), Instant::now()) {
+						debug!(target: "network", "Rejecting connection from {}: too many connections from its subnet", addr);
+						// Dropping with zero linger resets the connection.
+						let _ = sock.set_linger(Some(Duration::from_secs(0)));
+						continue;
+					}
+					sock
+				},
 				Err(e) => {
 					if e.kind() != io::ErrorKind::WouldBlock {
 						debug!(target: "network", "Error accepting connection: {:?}", e);
@@ -1056,6 +1081,14 @@ impl IoHandler<NetworkIoMessage> for Host {
 				self.nodes.write().clear_useless();
 				self.nodes.write().save();
 			},
+			NAT_REFRESH => {
+				trace!(target: "network", "Refreshing NAT port mapping");
+				if let Some(ref mapping) = *self.port_mapping.lock() {
+					if let Err(err) = mapping.refresh() {
+						warn!(target: "network", "Error refreshing NAT port mapping: {}", err);
+					}
+				}
+			},
 			_ => match self.timers.read().get(&token).cloned() {
 				Some(timer) => match self.handlers.read().get(&timer.protocol).cloned() {
 					None => { warn!(target: "network", "No handler found for protocol: {:?}", timer.protocol) },
@@ -18,9 +18,10 @@
 
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 use std::io;
-use igd::{PortMappingProtocol, search_gateway_from_timeout};
+use igd::{Gateway, PortMappingProtocol, search_gateway_from_timeout};
 use std::time::Duration;
 use node_table::NodeEndpoint;
+use nat_pmp;
 use ipnetwork::IpNetwork;
 
 /// Socket address extension for rustc beta. To be replaces with now unstable API
@@ -306,39 +307,178 @@ pub fn select_public_address(port: u16) -> SocketAddr {
 	SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), port))
 }
 
-pub fn map_external_address(local: &NodeEndpoint) -> Option<NodeEndpoint> {
+/// Lease requested for port mappings, in seconds. The host renews the mappings well before it runs out.
+pub const PORT_MAPPING_LEASE: u32 = 60 * 60;
+
+#[derive(Debug)]
+enum MappingGateway {
+	Upnp(Gateway),
+	NatPmp(SocketAddrV4),
+}
+
+/// TCP and UDP ports mapped on the gateway.
+#[derive(Debug)]
+pub struct PortMapping {
+	gateway: MappingGateway,
+	local: SocketAddrV4,
+	local_udp_port: u16,
+	/// Endpoint the node is reachable at from outside.
+	pub external: NodeEndpoint,
+}
+
+impl PortMapping {
+	/// Renew the lease of the mapped ports.
+	pub fn refresh(&self) -> Result<(), String> {
+		let tcp_port = self.external.address.port();
+		let udp_port = self.external.udp_port;
+		match self.gateway {
+			MappingGateway::Upnp(ref gateway) => {
+				let local_udp = SocketAddrV4::new(*self.local.ip(), self.local_udp_port);
+				gateway.add_port(PortMappingProtocol::TCP, tcp_port, self.local, PORT_MAPPING_LEASE, "Parity Node/TCP")
+					.and_then(|_| gateway.add_port(PortMappingProtocol::UDP, udp_port, local_udp, PORT_MAPPING_LEASE, "Parity Node/UDP"))
+					.map_err(|err| format!("{}", err))
+			},
+			MappingGateway::NatPmp(ref gateway) => {
+				let tcp = nat_pmp::map_port(gateway, nat_pmp::Protocol::Tcp, self.local.port(), tcp_port, PORT_MAPPING_LEASE);
+				let udp = nat_pmp::map_port(gateway, nat_pmp::Protocol::Udp, self.local_udp_port, udp_port, PORT_MAPPING_LEASE);
+				match (tcp, udp) {
+					(Ok(tcp), Ok(udp)) if tcp == tcp_port && udp == udp_port => Ok(()),
+					(Ok(_), Ok(_)) => Err("Gateway moved the mapping to another port".into()),
+					(Err(err), _) | (_, Err(err)) => Err(format!("{}", err)),
+				}
+			},
+		}
+	}
+
+	/// Remove the mapped ports from the gateway.
+	pub fn release(&self) {
+		let tcp_port = self.external.address.port();
+		let udp_port = self.external.udp_port;
+		match self.gateway {
+			MappingGateway::Upnp(ref gateway) => {
+				if let Err(ref err) = gateway.remove_port(PortMappingProtocol::TCP, tcp_port) {
+					debug!("Port mapping removal error: {}", err);
+				}
+				if let Err(ref err) = gateway.remove_port(PortMappingProtocol::UDP, udp_port) {
+					debug!("Port mapping removal error: {}", err);
+				}
+			},
+			MappingGateway::NatPmp(ref gateway) => {
+				if let Err(ref err) = nat_pmp::map_port(gateway, nat_pmp::Protocol::Tcp, self.local.port(), 0, 0) {
+					debug!("Port mapping removal error: {}", err);
+				}
+				if let Err(ref err) = nat_pmp::map_port(gateway, nat_pmp::Protocol::Udp, self.local_udp_port, 0, 0) {
+					debug!("Port mapping removal error: {}", err);
+				}
+			},
+		}
+	}
+}
+
+/// Map the local TCP and UDP ports on the gateway, through UPnP or else NAT-PMP.
+pub fn map_external_address(local: &NodeEndpoint) -> Option<PortMapping> {
 	if let SocketAddr::V4(ref local_addr) = local.address {
 		match search_gateway_from_timeout(*local_addr.ip(), Duration::new(5, 0)) {
 			Err(ref err) => debug!("Gateway search error: {}", err),
 			Ok(gateway) => {
-				match gateway.get_external_ip() {
-					Err(ref err) => {
-						debug!("IP request error: {}", err);
-					},
-					Ok(external_addr) => {
-						match gateway.add_any_port(PortMappingProtocol::TCP, SocketAddrV4::new(*local_addr.ip(), local_addr.port()), 0, "Parity Node/TCP") {
-							Err(ref err) => {
-								debug!("Port mapping error: {}", err);
-							},
-							Ok(tcp_port) => {
-								match gateway.add_any_port(PortMappingProtocol::UDP, SocketAddrV4::new(*local_addr.ip(), local.udp_port), 0, "Parity Node/UDP") {
-									Err(ref err) => {
-										debug!("Port mapping error: {}", err);
-									},
-									Ok(udp_port) => {
-										return Some(NodeEndpoint { address: SocketAddr::V4(SocketAddrV4::new(external_addr, tcp_port)), udp_port });
-									},
-								}
-							},
-						}
-					},
+				if let Some(mapping) = map_upnp(gateway, *local_addr, local.udp_port) {
+					return Some(mapping);
 				}
 			},
 		}
+		match default_gateway() {
+			None => debug!("Default gateway not found"),
+			Some(gateway) => return map_nat_pmp(SocketAddrV4::new(gateway, nat_pmp::NAT_PMP_PORT), *local_addr, local.udp_port),
+		}
 	}
 	None
 }
 
+fn map_upnp(gateway: Gateway, local: SocketAddrV4, local_udp_port: u16) -> Option<PortMapping> {
+	match gateway.get_external_ip() {
+		Err(ref err) => {
+			debug!("IP request error: {}", err);
+		},
+		Ok(external_addr) => {
+			match gateway.add_any_port(PortMappingProtocol::TCP, local, PORT_MAPPING_LEASE, "Parity Node/TCP") {
+				Err(ref err) => {
+					debug!("Port mapping error: {}", err);
+				},
+				Ok(tcp_port) => {
+					match gateway.add_any_port(PortMappingProtocol::UDP, SocketAddrV4::new(*local.ip(), local_udp_port), PORT_MAPPING_LEASE, "Parity Node/UDP") {
+						Err(ref err) => {
+							debug!("Port mapping error: {}", err);
+							let _ = gateway.remove_port(PortMappingProtocol::TCP, tcp_port);
+						},
+						Ok(udp_port) => {
+							return Some(PortMapping {
+								gateway: MappingGateway::Upnp(gateway),
+								local,
+								local_udp_port,
+								external: NodeEndpoint { address: SocketAddr::V4(SocketAddrV4::new(external_addr, tcp_port)), udp_port },
+							});
+						},
+					}
+				},
+			}
+		},
+	}
+	None
+}
+
+fn map_nat_pmp(gateway: SocketAddrV4, local: SocketAddrV4, local_udp_port: u16) -> Option<PortMapping> {
+	match nat_pmp::external_address(&gateway) {
+		Err(ref err) => {
+			debug!("NAT-PMP address request error: {}", err);
+		},
+		Ok(external_addr) => {
+			match nat_pmp::map_port(&gateway, nat_pmp::Protocol::Tcp, local.port(), local.port(), PORT_MAPPING_LEASE) {
+				Err(ref err) => {
+					debug!("NAT-PMP port mapping error: {}", err);
+				},
+				Ok(tcp_port) => {
+					match nat_pmp::map_port(&gateway, nat_pmp::Protocol::Udp, local_udp_port, local_udp_port, PORT_MAPPING_LEASE) {
+						Err(ref err) => {
+							debug!("NAT-PMP port mapping error: {}", err);
+							let _ = nat_pmp::map_port(&gateway, nat_pmp::Protocol::Tcp, local.port(), 0, 0);
+						},
+						Ok(udp_port) => {
+							return Some(PortMapping {
+								gateway: MappingGateway::NatPmp(gateway),
+								local,
+								local_udp_port,
+								external: NodeEndpoint { address: SocketAddr::V4(SocketAddrV4::new(external_addr, tcp_port)), udp_port },
+							});
+						},
+					}
+				},
+			}
+		},
+	}
+	None
+}
+
+/// Default IPv4 gateway from the kernel routing table.
+#[cfg(target_os = "linux")]
+fn default_gateway() -> Option<Ipv4Addr> {
+	use std::fs::File;
+	use std::io::Read;
+
+	let mut routes = String::new();
+	File::open("/proc/net/route").and_then(|mut f| f.read_to_string(&mut routes)).ok()?;
+	// Iface Destination Gateway Flags ...; addresses are hex in host byte order
+	routes.lines().skip(1)
+		.map(|line| line.split_whitespace().collect::<Vec<_>>())
+		.find(|fields| fields.len() > 2 && fields[1] == "00000000")
+		.and_then(|fields| u32::from_str_radix(fields[2], 16).ok())
+		.map(|gateway| Ipv4Addr::from(u32::from_be(gateway)))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn default_gateway() -> Option<Ipv4Addr> {
+	None
+}
+
 #[test]
 fn can_select_public_address() {
 	let pub_address = select_public_address(40477);
@@ -352,6 +492,160 @@ fn can_map_external_address_or_fail() {
 	let _ = map_external_address(&NodeEndpoint { address: pub_address, udp_port: 40478 });
 }
 
+#[cfg(test)]
+fn mock_upnp_gateway() -> (Gateway, ::std::sync::Arc<::parking_lot::Mutex<Vec<(String, Option<String>)>>>) {
+	use std::io::{Read, Write};
+	use std::net::TcpListener;
+	use std::sync::Arc;
+	use std::thread;
+	use parking_lot::Mutex;
+
+	fn element<'a>(body: &'a str, name: &str) -> Option<&'a str> {
+		let open = format!("<{}>", name);
+		let close = format!("</{}>", name);
+		let start = body.find(&open)? + open.len();
+		let end = body[start..].find(&close)? + start;
+		Some(&body[start..end])
+	}
+
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let addr = match listener.local_addr().unwrap() {
+		SocketAddr::V4(addr) => addr,
+		SocketAddr::V6(_) => unreachable!(),
+	};
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let log = requests.clone();
+	thread::spawn(move || {
+		for stream in listener.incoming() {
+			let mut stream = stream.unwrap();
+			let mut request = Vec::new();
+			let mut buf = [0u8; 4096];
+			// read the headers, then as much of the body as Content-Length says
+			let body_start = loop {
+				let len = stream.read(&mut buf).unwrap();
+				request.extend_from_slice(&buf[..len]);
+				if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+					break pos + 4;
+				}
+			};
+			let content_length = String::from_utf8_lossy(&request[..body_start]).lines()
+				.filter_map(|line| {
+					let mut parts = line.splitn(2, ':');
+					match (parts.next(), parts.next()) {
+						(Some(name), Some(value)) if name.eq_ignore_ascii_case("content-length") => value.trim().parse::<usize>().ok(),
+						_ => None,
+					}
+				})
+				.next()
+				.unwrap_or(0);
+			while request.len() < body_start + content_length {
+				let len = stream.read(&mut buf).unwrap();
+				request.extend_from_slice(&buf[..len]);
+			}
+			let request = String::from_utf8_lossy(&request).into_owned();
+
+			let (action, result) = if request.contains("DeletePortMapping") {
+				("DeletePortMapping", String::new())
+			} else if request.contains("AddAnyPortMapping") {
+				("AddAnyPortMapping", "<NewReservedPort>40404</NewReservedPort>".to_owned())
+			} else if request.contains("AddPortMapping") {
+				("AddPortMapping", String::new())
+			} else {
+				("GetExternalIPAddress", "<NewExternalIPAddress>203.0.113.7</NewExternalIPAddress>".to_owned())
+			};
+			log.lock().push((action.to_owned(), element(&request, "NewLeaseDuration").map(ToOwned::to_owned)));
+
+			let body = format!(
+				"<?xml version=\"1.0\"?>\
+				<s:Envelope xmlns:s=\"http://schemas.xmlsoap.org/soap/envelope/\" s:encodingStyle=\"http://schemas.xmlsoap.org/soap/encoding/\">\
+				<s:Body><u:{0}Response xmlns:u=\"urn:schemas-upnp-org:service:WANIPConnection:1\">{1}</u:{0}Response></s:Body>\
+				</s:Envelope>",
+				action, result);
+			let response = format!("HTTP/1.1 200 OK\r\nContent-Type: text/xml; charset=\"utf-8\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+			stream.write_all(response.as_bytes()).unwrap();
+		}
+	});
+
+	(Gateway { addr, control_url: "/control".into() }, requests)
+}
+
+#[test]
+fn can_map_refresh_and_release_upnp_ports() {
+	let (gateway, requests) = mock_upnp_gateway();
+	let local = SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 10), 30303);
+
+	let mapping = map_upnp(gateway, local, 30304).expect("mock gateway maps the ports");
+	assert_eq!(mapping.external, NodeEndpoint {
+		address: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(203, 0, 113, 7), 40404)),
+		udp_port: 40404,
+	});
+	mapping.refresh().unwrap();
+	mapping.release();
+
+	let lease = Some(format!("{}", PORT_MAPPING_LEASE));
+	assert_eq!(*requests.lock(), vec![
+		("GetExternalIPAddress".to_owned(), None),
+		("AddAnyPortMapping".to_owned(), lease.clone()),
+		("AddAnyPortMapping".to_owned(), lease.clone()),
+		("AddPortMapping".to_owned(), lease.clone()),
+		("AddPortMapping".to_owned(), lease.clone()),
+		("DeletePortMapping".to_owned(), None),
+		("DeletePortMapping".to_owned(), None),
+	]);
+}
+
+#[test]
+fn can_map_refresh_and_release_nat_pmp_ports() {
+	use std::net::UdpSocket;
+	use std::sync::Arc;
+	use std::thread;
+	use parking_lot::Mutex;
+
+	let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+	let gateway = match socket.local_addr().unwrap() {
+		SocketAddr::V4(addr) => addr,
+		SocketAddr::V6(_) => unreachable!(),
+	};
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let log = requests.clone();
+	thread::spawn(move || {
+		let mut buf = [0u8; 12];
+		loop {
+			let (len, from) = socket.recv_from(&mut buf).unwrap();
+			log.lock().push(buf[..len].to_vec());
+			let mut response = vec![0, 128 + buf[1], 0, 0, 0, 0, 0, 1];
+			if buf[1] == 0 {
+				response.extend_from_slice(&[203, 0, 113, 7]);
+			} else {
+				// grant the suggested external port + 1 with the requested lifetime
+				let external = if len == 12 && buf[6..8] != [0, 0] { ((buf[6] as u16) << 8 | buf[7] as u16) + 1 } else { 0 };
+				response.extend_from_slice(&buf[4..6]);
+				response.extend_from_slice(&[(external >> 8) as u8, external as u8]);
+				response.extend_from_slice(&buf[8..12]);
+			}
+			socket.send_to(&response, from).unwrap();
+		}
+	});
+
+	let local = SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 10), 30303);
+	let mapping = map_nat_pmp(gateway, local, 30304).expect("mock gateway maps the ports");
+	assert_eq!(mapping.external, NodeEndpoint {
+		address: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(203, 0, 113, 7), 30304)),
+		udp_port: 30305,
+	});
+	mapping.release();
+
+	let lease = PORT_MAPPING_LEASE;
+	let lease = [(lease >> 24) as u8, (lease >> 16) as u8, (lease >> 8) as u8, lease as u8];
+	assert_eq!(*requests.lock(), vec![
+		vec![0, 0],
+		vec![0, 2, 0, 0, 0x76, 0x5f, 0x76, 0x5f, lease[0], lease[1], lease[2], lease[3]],
+		vec![0, 1, 0, 0, 0x76, 0x60, 0x76, 0x60, lease[0], lease[1], lease[2], lease[3]],
+		vec![0, 2, 0, 0, 0x76, 0x5f, 0, 0, 0, 0, 0, 0],
+		vec![0, 1, 0, 0, 0x76, 0x60, 0, 0, 0, 0, 0, 0],
+	]);
+}
+
 #[test]
 fn ipv4_properties() {
 	fn check(octets: &[u8; 4], unspec: bool, loopback: bool,
@@ -106,6 +106,8 @@ mod discovery;
 mod service;
 mod node_table;
 mod ip_utils;
+mod nat_pmp;
+mod connection_limiter;
 
 pub use service::NetworkService;
 pub use host::NetworkContext;
@@ -0,0 +1,90 @@
+// Copyright 2015-2018 Parity Technologies (UK) Ltd.
+// This file is part of Parity.
+
+// Parity is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Minimal NAT-PMP (RFC 6886) client, used when the gateway does not answer UPnP.
+
+use std::io;
+use std::net::{Ipv4Addr, SocketAddrV4, UdpSocket};
+use std::time::Duration;
+
+/// Port the gateway listens on for NAT-PMP requests.
+pub const NAT_PMP_PORT: u16 = 5351;
+
+const OP_EXTERNAL_ADDRESS: u8 = 0;
+// response opcode is the request opcode + 128
+const OP_RESPONSE: u8 = 128;
+// RFC 6886 starts at 250ms and doubles the timeout on every retry
+const INITIAL_TIMEOUT: u64 = 250;
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Transport protocol of a port mapping.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Protocol {
+	Udp = 1,
+	Tcp = 2,
+}
+
+fn read_u16(buf: &[u8]) -> u16 {
+	(buf[0] as u16) << 8 | buf[1] as u16
+}
+
+fn request(gateway: &SocketAddrV4, req: &[u8], response_len: usize) -> io::Result<Vec<u8>> {
+	let socket = UdpSocket::bind("0.0.0.0:0")?;
+	socket.connect(gateway)?;
+	let mut timeout = Duration::from_millis(INITIAL_TIMEOUT);
+	let mut buf = [0u8; 16];
+	for _ in 0..MAX_ATTEMPTS {
+		socket.set_read_timeout(Some(timeout))?;
+		socket.send(req)?;
+		match socket.recv(&mut buf) {
+			Ok(len) => {
+				if len < response_len || buf[0] != 0 || buf[1] != OP_RESPONSE + req[1] {
+					return Err(io::Error::new(io::ErrorKind::InvalidData, "Malformed NAT-PMP response"));
+				}
+				let result = read_u16(&buf[2..4]);
+				if result != 0 {
+					return Err(io::Error::new(io::ErrorKind::Other, format!("NAT-PMP request failed with result code {}", result)));
+				}
+				return Ok(buf[..len].to_vec());
+			},
+			Err(ref e) if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+				timeout = timeout * 2;
+			},
+			Err(e) => return Err(e),
+		}
+	}
+	Err(io::Error::new(io::ErrorKind::TimedOut, "No NAT-PMP response from the gateway"))
+}
+
+/// Ask the gateway for its external address.
+pub fn external_address(gateway: &SocketAddrV4) -> io::Result<Ipv4Addr> {
+	let response = request(gateway, &[0, OP_EXTERNAL_ADDRESS], 12)?;
+	Ok(Ipv4Addr::new(response[8], response[9], response[10], response[11]))
+}
+
+/// Map `internal_port` for `lifetime` seconds, preferably to `external_port`.
+/// Returns the external port assigned by the gateway.
+/// A zero `lifetime` and `external_port` delete the mapping.
+pub fn map_port(gateway: &SocketAddrV4, protocol: Protocol, internal_port: u16, external_port: u16, lifetime: u32) -> io::Result<u16> {
+	let req = [
+		0, protocol as u8, 0, 0,
+		(internal_port >> 8) as u8, internal_port as u8,
+		(external_port >> 8) as u8, external_port as u8,
+		(lifetime >> 24) as u8, (lifetime >> 16) as u8, (lifetime >> 8) as u8, lifetime as u8,
+	];
+	let response = request(gateway, &req, 16)?;
+	Ok(read_u16(&response[10..12]))
+}
@@ -193,6 +193,10 @@ pub struct NetworkConfiguration {
 	pub max_peers: u32,
 	/// Maximum handshakes
 	pub max_handshakes: u32,
+	/// Maximum number of incoming connections per /24 (IPv4) or /48 (IPv6) subnet within `subnet_connection_window`. 0 disables the limit.
+	pub max_connections_per_subnet: u32,
+	/// Window for `max_connections_per_subnet`.
+	pub subnet_connection_window: Duration,
 	/// Reserved protocols. Peers with <key> protocol get additional <value> connection slots.
 	pub reserved_protocols: HashMap<ProtocolId, u32>,
 	/// List of reserved node addresses.
@@ -227,6 +231,8 @@ impl NetworkConfiguration {
 			min_peers: 25,
 			max_peers: 50,
 			max_handshakes: 64,
+			max_connections_per_subnet: 4,
+			subnet_connection_window: Duration::from_secs(10),
 			reserved_protocols: HashMap::new(),
 			ip_filter: IpFilter::default(),
 			reserved_nodes: Vec::new(),